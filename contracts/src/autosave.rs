@@ -1,9 +1,25 @@
+use crate::calculate_fee;
+use crate::ensure_not_paused;
 use crate::errors::SavingsError;
+use crate::events;
 use crate::flexi;
-use crate::storage_types::{AutoSave, DataKey};
+use crate::storage_types::{AutoSave, AutoSaveReceipt, DataKey};
 use crate::ttl;
 use crate::users;
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Vec};
+
+/// Maximum number of schedule IDs returned by a single `get_all_autosaves` page
+pub const MAX_AUTOSAVE_PAGE_SIZE: u32 = 100;
+
+/// AutoSave state kept outside `AutoSave`, so existing records still decode
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AutoSaveKey {
+    /// Set while a live schedule is paused by `pause_all_autosaves`
+    Paused(u64),
+    /// Rewards credited to a keeper for executing schedules
+    KeeperRewards(Address),
+}
 
 /// Creates a new AutoSave schedule for recurring Flexi deposits
 ///
@@ -12,7 +28,8 @@ use soroban_sdk::{Address, Env, Vec};
 /// * `user` - The user creating the schedule
 /// * `amount` - The amount to deposit on each execution (must be > 0)
 /// * `interval_seconds` - How often the schedule runs in seconds (must be > 0)
-/// * `start_time` - Unix timestamp for the first execution
+/// * `start_time` - Unix timestamp for the first execution; may be in the past
+///   by less than one interval
 ///
 /// # Returns
 /// * `Ok(u64)` - The unique schedule ID
@@ -24,15 +41,22 @@ pub fn create_autosave(
     interval_seconds: u64,
     start_time: u64,
 ) -> Result<u64, SavingsError> {
+    // Rejects both a pause and an emergency shutdown
+    ensure_not_paused(env)?;
     user.require_auth();
 
-    // Validate amount
-    if amount <= 0 {
+    // Validate amount, including the Flexi minimum every run deposits into
+    if amount <= 0 || amount < flexi::get_min_flexi_deposit(env).unwrap_or(0) {
         return Err(SavingsError::InvalidAmount);
     }
 
     // Validate interval
     if interval_seconds == 0 {
+        return Err(SavingsError::InvalidDuration);
+    }
+
+    // Reject start times so far back that a whole interval has already been missed
+    if start_time.saturating_add(interval_seconds) <= env.ledger().timestamp() {
         return Err(SavingsError::InvalidTimestamp);
     }
 
@@ -59,8 +83,9 @@ pub fn create_autosave(
         .persistent()
         .set(&DataKey::AutoSave(schedule_id), &schedule);
 
-    // Link schedule to user
+    // Link schedule to user and the global index
     add_schedule_to_user(env, &user, schedule_id);
+    add_schedule_to_global_index(env, schedule_id);
 
     // Increment the next schedule ID
     increment_next_schedule_id(env);
@@ -72,6 +97,44 @@ pub fn create_autosave(
     Ok(schedule_id)
 }
 
+/// Creates an AutoSave schedule like `create_autosave`, returning a receipt
+/// with the schedule ID, first execution time and interval so callers need
+/// no follow-up read
+pub fn create_autosave_v2(
+    env: &Env,
+    user: Address,
+    amount: i128,
+    interval_seconds: u64,
+    start_time: u64,
+) -> Result<AutoSaveReceipt, SavingsError> {
+    let schedule_id = create_autosave(env, user, amount, interval_seconds, start_time)?;
+    let schedule = get_autosave(env, schedule_id).ok_or(SavingsError::InternalError)?;
+
+    Ok(AutoSaveReceipt {
+        schedule_id,
+        first_execution_time: schedule.next_execution_time,
+        interval_seconds: schedule.interval_seconds,
+    })
+}
+
+/// Creates an AutoSave schedule whose first run is one interval from now
+///
+/// Convenience over `create_autosave` for callers that don't need a custom
+/// start time.
+pub fn create_autosave_now(
+    env: &Env,
+    user: Address,
+    amount: i128,
+    interval_seconds: u64,
+) -> Result<u64, SavingsError> {
+    let start_time = env
+        .ledger()
+        .timestamp()
+        .checked_add(interval_seconds)
+        .ok_or(SavingsError::Overflow)?;
+    create_autosave(env, user, amount, interval_seconds, start_time)
+}
+
 /// Executes an AutoSave schedule if it's due
 ///
 /// # Arguments
@@ -82,41 +145,111 @@ pub fn create_autosave(
 /// * `Ok(())` - If execution succeeds
 /// * `Err(SavingsError)` - If the schedule is not found, inactive, or not yet due
 pub fn execute_autosave(env: &Env, schedule_id: u64) -> Result<(), SavingsError> {
-    // Fetch the schedule
-    let mut schedule: AutoSave = env
-        .storage()
-        .persistent()
-        .get(&DataKey::AutoSave(schedule_id))
-        .ok_or(SavingsError::PlanNotFound)?;
+    // Anonymous execution is only allowed while no keepers are registered
+    if !get_keepers(env).is_empty() {
+        return Err(SavingsError::Unauthorized);
+    }
 
-    // Ensure schedule is active
-    if !schedule.is_active {
-        return Err(SavingsError::InvalidPlanConfig);
+    run_schedule(env, schedule_id, None)?;
+    Ok(())
+}
+
+/// Executes an AutoSave schedule on behalf of a keeper, paying them a reward
+///
+/// The reward is `KeeperRewardRate` bps of the scheduled amount and is
+/// deducted from the deposit, so the user's Flexi balance receives the net.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `keeper` - The address executing the schedule and receiving the reward
+/// * `schedule_id` - The ID of the schedule to execute
+///
+/// # Returns
+/// * `Ok(i128)` - The reward credited to the keeper
+/// * `Err(SavingsError)` - If the schedule is not found, inactive, or not yet due
+pub fn keeper_execute_autosave(
+    env: &Env,
+    keeper: Address,
+    schedule_id: u64,
+) -> Result<i128, SavingsError> {
+    keeper.require_auth();
+
+    let keepers = get_keepers(env);
+    if !keepers.is_empty() && !keepers.contains(&keeper) {
+        return Err(SavingsError::Unauthorized);
     }
 
-    // Ensure current time >= next_execution_time
-    let current_time = env.ledger().timestamp();
-    if current_time < schedule.next_execution_time {
-        return Err(SavingsError::InvalidTimestamp);
+    run_schedule(env, schedule_id, Some(&keeper))
+}
+
+/// Registers `keeper` as allowed to execute schedules (admin only)
+///
+/// Once at least one keeper is registered, execution is restricted to the
+/// whitelist; with an empty whitelist anyone may execute due schedules.
+pub fn add_keeper(env: &Env, keeper: Address) -> Result<(), SavingsError> {
+    require_admin(env)?;
+
+    let mut keepers = get_keepers(env);
+    if !keepers.contains(&keeper) {
+        keepers.push_back(keeper);
+        env.storage()
+            .instance()
+            .set(&DataKey::KeeperWhitelist, &keepers);
     }
+    Ok(())
+}
 
-    // Perform Flexi deposit
-    flexi::flexi_deposit(env.clone(), schedule.user.clone(), schedule.amount)?;
+/// Removes `keeper` from the whitelist (admin only)
+pub fn remove_keeper(env: &Env, keeper: Address) -> Result<(), SavingsError> {
+    require_admin(env)?;
 
-    // Update next execution time
-    schedule.next_execution_time += schedule.interval_seconds;
+    let mut keepers = get_keepers(env);
+    if let Some(index) = keepers.first_index_of(&keeper) {
+        keepers.remove(index);
+        env.storage()
+            .instance()
+            .set(&DataKey::KeeperWhitelist, &keepers);
+    }
+    Ok(())
+}
 
-    // Save updated schedule
+/// Gets the registered keepers (empty means execution is permissionless)
+pub fn get_keepers(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::KeeperWhitelist)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Gets the accumulated rewards credited to a keeper
+pub fn get_keeper_rewards(env: &Env, keeper: &Address) -> i128 {
     env.storage()
         .persistent()
-        .set(&DataKey::AutoSave(schedule_id), &schedule);
+        .get(&AutoSaveKey::KeeperRewards(keeper.clone()))
+        .unwrap_or(0)
+}
 
-    // Extend TTL on execution (active schedule gets full extension)
-    ttl::extend_autosave_ttl(env, schedule_id);
+/// Sets the keeper reward rate in basis points (admin only)
+pub fn set_keeper_reward_rate(env: &Env, bps: u32) -> Result<(), SavingsError> {
+    require_admin(env)?;
 
+    if bps > 10_000 {
+        return Err(SavingsError::InvalidFeeBps);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::KeeperRewardRate, &bps);
     Ok(())
 }
 
+/// Gets the keeper reward rate in basis points (0 if unset)
+pub fn get_keeper_reward_rate(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::KeeperRewardRate)
+        .unwrap_or(0)
+}
+
 /// Batch-executes multiple AutoSave schedules that are due.
 ///
 /// This function is designed to be called by an external bot or relayer to
@@ -131,20 +264,50 @@ pub fn execute_autosave(env: &Env, schedule_id: u64) -> Result<(), SavingsError>
 /// A `Vec<bool>` where each element corresponds to the schedule at the same
 /// index in `schedule_ids`:
 /// - `true`  — the schedule was due and executed successfully
-/// - `false` — the schedule was skipped (not found, inactive, not yet due, or deposit failed)
+/// - `false` — the schedule was skipped (not found, inactive, not yet due, deposit failed,
+///   or already listed earlier in `schedule_ids`)
 ///
 /// # Guarantees
+/// - While a keeper whitelist is configured this anonymous entry point skips
+///   every schedule; whitelisted keepers use `keeper_execute_autosave`.
+/// - At most `events::MAX_BATCH_ITEMS` schedules are processed per call; any
+///   further IDs are reported as `false` untouched, to be retried next call.
 /// - One failed or skipped schedule does **not** revert the entire batch.
 /// - Only schedules whose `next_execution_time <= current_ledger_timestamp` are executed.
 /// - For each executed schedule, a Flexi deposit is performed and `next_execution_time` is
 ///   advanced by `interval_seconds`.
+/// - A `batch` summary event with the processed and executed counts is
+///   published after the per-schedule deposit events.
 pub fn execute_due_autosaves(env: &Env, schedule_ids: Vec<u64>) -> Vec<bool> {
     let current_time = env.ledger().timestamp();
     let mut results = Vec::new(env);
 
+    if !get_keepers(env).is_empty() {
+        for _ in 0..schedule_ids.len() {
+            results.push_back(false);
+        }
+        events::emit_batch_summary(env, symbol_short!("autosave"), 0, 0);
+        return results;
+    }
+
+    let processed = schedule_ids.len().min(events::MAX_BATCH_ITEMS);
+    let mut succeeded: u32 = 0;
+    let mut seen: Vec<u64> = Vec::new(env);
     for i in 0..schedule_ids.len() {
         let schedule_id = schedule_ids.get(i).unwrap();
 
+        if i >= processed {
+            results.push_back(false);
+            continue;
+        }
+
+        // A schedule several intervals overdue must still run once per batch
+        if seen.contains(schedule_id) {
+            results.push_back(false);
+            continue;
+        }
+        seen.push_back(schedule_id);
+
         // Attempt to fetch the schedule; skip if not found
         let maybe_schedule: Option<AutoSave> = env
             .storage()
@@ -159,8 +322,8 @@ pub fn execute_due_autosaves(env: &Env, schedule_ids: Vec<u64>) -> Vec<bool> {
             }
         };
 
-        // Skip inactive schedules
-        if !schedule.is_active {
+        // Skip cancelled and paused schedules
+        if !schedule.is_active || is_autosave_paused(env, schedule_id) {
             results.push_back(false);
             continue;
         }
@@ -187,14 +350,20 @@ pub fn execute_due_autosaves(env: &Env, schedule_ids: Vec<u64>) -> Vec<bool> {
             .persistent()
             .set(&DataKey::AutoSave(schedule_id), &updated_schedule);
 
+        succeeded += 1;
         results.push_back(true);
     }
 
+    events::emit_batch_summary(env, symbol_short!("autosave"), processed, succeeded);
     results
 }
 
 /// Cancels an AutoSave schedule
 ///
+/// The schedule is deactivated and removed from the user's index, so
+/// `get_user_autosaves` only lists live schedules. The record itself is kept
+/// and stays readable through `get_autosave`.
+///
 /// # Arguments
 /// * `env` - The contract environment
 /// * `user` - The user cancelling the schedule
@@ -226,9 +395,228 @@ pub fn cancel_autosave(env: &Env, user: Address, schedule_id: u64) -> Result<(),
         .persistent()
         .set(&DataKey::AutoSave(schedule_id), &schedule);
 
+    // Unlink schedule from the user's index
+    remove_schedule_from_user(env, &user, schedule_id);
+    env.storage()
+        .persistent()
+        .remove(&AutoSaveKey::Paused(schedule_id));
+
+    Ok(())
+}
+
+/// Changes the amount and interval of an existing AutoSave schedule
+///
+/// The already-scheduled `next_execution_time` is kept, so the next run happens
+/// when it would have anyway; only the gaps after that run use the new interval.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `user` - The owner of the schedule
+/// * `schedule_id` - The ID of the schedule to update
+/// * `amount` - The new amount deposited on each execution (must be > 0)
+/// * `interval_seconds` - The new interval in seconds (must be > 0)
+///
+/// # Returns
+/// * `Ok(())` - If the update succeeds
+/// * `Err(SavingsError)` - If validation fails, the schedule is not found,
+///   or `user` is not the owner
+pub fn update_autosave(
+    env: &Env,
+    user: Address,
+    schedule_id: u64,
+    amount: i128,
+    interval_seconds: u64,
+) -> Result<(), SavingsError> {
+    user.require_auth();
+
+    if amount <= 0 || amount < flexi::get_min_flexi_deposit(env).unwrap_or(0) {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    if interval_seconds == 0 {
+        return Err(SavingsError::InvalidDuration);
+    }
+
+    let mut schedule: AutoSave = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AutoSave(schedule_id))
+        .ok_or(SavingsError::PlanNotFound)?;
+
+    if schedule.user != user {
+        return Err(SavingsError::Unauthorized);
+    }
+
+    schedule.amount = amount;
+    schedule.interval_seconds = interval_seconds;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::AutoSave(schedule_id), &schedule);
+    ttl::extend_autosave_ttl(env, schedule_id);
+
     Ok(())
 }
 
+/// Re-activates a cancelled AutoSave schedule, keeping its ID and history
+///
+/// The next execution is moved forward to the first slot on the schedule's
+/// original cadence at or after now, so missed runs are not replayed.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `user` - The owner of the schedule
+/// * `schedule_id` - The ID of the cancelled schedule
+///
+/// # Returns
+/// * `Ok(())` - If the schedule is active again
+/// * `Err(SavingsError)` - If the schedule is not found, not owned by `user`,
+///   or already active
+pub fn reactivate_autosave(env: &Env, user: Address, schedule_id: u64) -> Result<(), SavingsError> {
+    user.require_auth();
+
+    let mut schedule: AutoSave = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AutoSave(schedule_id))
+        .ok_or(SavingsError::PlanNotFound)?;
+
+    if schedule.user != user {
+        return Err(SavingsError::Unauthorized);
+    }
+
+    if schedule.is_active {
+        return Err(SavingsError::InvalidPlanConfig);
+    }
+
+    schedule.next_execution_time = next_run_at_or_after(&schedule, env.ledger().timestamp())?;
+    schedule.is_active = true;
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::AutoSave(schedule_id), &schedule);
+
+    add_schedule_to_user(env, &user, schedule_id);
+    ttl::extend_autosave_ttl(env, schedule_id);
+
+    Ok(())
+}
+
+/// Cancels several AutoSave schedules owned by `user` in one call
+///
+/// IDs that do not exist, belong to another user, or are already cancelled
+/// are skipped rather than aborting the batch. Paused schedules can be
+/// cancelled, and are no longer paused afterwards.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `user` - The user cancelling the schedules
+/// * `schedule_ids` - The IDs of the schedules to cancel
+///
+/// # Returns
+/// The IDs that were cancelled by this call, in input order
+pub fn cancel_autosaves(env: &Env, user: Address, schedule_ids: Vec<u64>) -> Vec<u64> {
+    user.require_auth();
+
+    let mut cancelled = Vec::new(env);
+
+    for schedule_id in schedule_ids.iter() {
+        let key = DataKey::AutoSave(schedule_id);
+        let mut schedule: AutoSave = match env.storage().persistent().get(&key) {
+            Some(s) => s,
+            None => continue,
+        };
+
+        if schedule.user != user || !schedule.is_active {
+            continue;
+        }
+
+        schedule.is_active = false;
+        env.storage().persistent().set(&key, &schedule);
+        remove_schedule_from_user(env, &user, schedule_id);
+        env.storage()
+            .persistent()
+            .remove(&AutoSaveKey::Paused(schedule_id));
+
+        cancelled.push_back(schedule_id);
+    }
+
+    cancelled
+}
+
+/// Pauses every active AutoSave schedule of `user`, e.g. while on holiday
+///
+/// Paused schedules stay active and in the user's index, but carry a pause
+/// flag that execution honours until `resume_all_autosaves` clears it.
+///
+/// Cancelled schedules are skipped. Schedules cancelled before
+/// `cancel_autosave` unlinked them can still sit in the index, so
+/// `is_active` is checked rather than index membership.
+///
+/// # Returns
+/// The IDs that were paused by this call
+pub fn pause_all_autosaves(env: &Env, user: Address) -> Vec<u64> {
+    user.require_auth();
+
+    let mut paused = Vec::new(env);
+    for schedule_id in get_user_autosaves(env, &user).iter() {
+        let Some(schedule) = env
+            .storage()
+            .persistent()
+            .get::<_, AutoSave>(&DataKey::AutoSave(schedule_id))
+        else {
+            continue;
+        };
+        if !schedule.is_active || is_autosave_paused(env, schedule_id) {
+            continue;
+        }
+
+        env.storage()
+            .persistent()
+            .set(&AutoSaveKey::Paused(schedule_id), &true);
+        paused.push_back(schedule_id);
+    }
+
+    paused
+}
+
+/// Resumes every paused AutoSave schedule of `user`
+///
+/// Runs missed while paused are skipped: each schedule's
+/// `next_execution_time` rolls forward by whole intervals to the first one
+/// at or after now, keeping its original cadence. Cancelled schedules are
+/// never resumed.
+///
+/// # Returns
+/// The IDs that were resumed by this call
+pub fn resume_all_autosaves(env: &Env, user: Address) -> Vec<u64> {
+    user.require_auth();
+
+    let now = env.ledger().timestamp();
+    let mut resumed = Vec::new(env);
+    for schedule_id in get_user_autosaves(env, &user).iter() {
+        let key = DataKey::AutoSave(schedule_id);
+        let Some(mut schedule) = env.storage().persistent().get::<_, AutoSave>(&key) else {
+            continue;
+        };
+        if !schedule.is_active || !is_autosave_paused(env, schedule_id) {
+            continue;
+        }
+        let Ok(next_run) = next_run_at_or_after(&schedule, now) else {
+            continue;
+        };
+
+        schedule.next_execution_time = next_run;
+        env.storage().persistent().set(&key, &schedule);
+        env.storage()
+            .persistent()
+            .remove(&AutoSaveKey::Paused(schedule_id));
+        resumed.push_back(schedule_id);
+    }
+
+    resumed
+}
+
 /// Gets an AutoSave schedule by ID
 pub fn get_autosave(env: &Env, schedule_id: u64) -> Option<AutoSave> {
     let schedule = env
@@ -244,7 +632,7 @@ pub fn get_autosave(env: &Env, schedule_id: u64) -> Option<AutoSave> {
     schedule
 }
 
-/// Gets all AutoSave schedule IDs for a user
+/// Gets the IDs of a user's non-cancelled AutoSave schedules
 pub fn get_user_autosaves(env: &Env, user: &Address) -> Vec<u64> {
     let list_key = DataKey::UserAutoSaves(user.clone());
     let schedules = env
@@ -261,8 +649,143 @@ pub fn get_user_autosaves(env: &Env, user: &Address) -> Vec<u64> {
     schedules
 }
 
+/// Gets a page of all AutoSave schedule IDs across every user
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `start` - Index of the first schedule ID to return
+/// * `limit` - Maximum number of IDs to return (capped at MAX_AUTOSAVE_PAGE_SIZE)
+///
+/// # Returns
+/// Schedule IDs in creation order; empty if `start` is past the end
+pub fn get_all_autosaves(env: &Env, start: u32, limit: u32) -> Vec<u64> {
+    let list_key = DataKey::AllAutoSaveIds;
+    let all: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&list_key)
+        .unwrap_or(Vec::new(env));
+
+    if !all.is_empty() {
+        ttl::extend_user_plan_list_ttl(env, &list_key);
+    }
+
+    if start >= all.len() {
+        return Vec::new(env);
+    }
+
+    let end = start
+        .saturating_add(limit.min(MAX_AUTOSAVE_PAGE_SIZE))
+        .min(all.len());
+    all.slice(start..end)
+}
+
+/// Returns the ID the next created schedule will receive, without reserving it
+pub fn peek_next_autosave_id(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::NextAutoSaveId)
+        .unwrap_or(1)
+}
+
+/// Next execution time of an active schedule; `None` if cancelled, paused
+/// or missing
+pub fn next_autosave_run(env: &Env, schedule_id: u64) -> Option<u64> {
+    let schedule: AutoSave = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AutoSave(schedule_id))?;
+
+    (schedule.is_active && !is_autosave_paused(env, schedule_id))
+        .then_some(schedule.next_execution_time)
+}
+
+/// Whether the schedule is paused by `pause_all_autosaves`
+pub fn is_autosave_paused(env: &Env, schedule_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&AutoSaveKey::Paused(schedule_id))
+}
+
 // ========== Helper Functions ==========
 
+fn require_admin(env: &Env) -> Result<(), SavingsError> {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(SavingsError::Unauthorized)?;
+    admin.require_auth();
+    Ok(())
+}
+
+/// Runs a due schedule, paying `keeper` (if any) its reward out of the deposit.
+///
+/// Returns the reward paid.
+fn run_schedule(
+    env: &Env,
+    schedule_id: u64,
+    keeper: Option<&Address>,
+) -> Result<i128, SavingsError> {
+    // Fetch the schedule
+    let mut schedule: AutoSave = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AutoSave(schedule_id))
+        .ok_or(SavingsError::PlanNotFound)?;
+
+    // Ensure schedule is active and not paused
+    if !schedule.is_active || is_autosave_paused(env, schedule_id) {
+        return Err(SavingsError::InvalidPlanConfig);
+    }
+
+    // Ensure current time >= next_execution_time
+    let current_time = env.ledger().timestamp();
+    if current_time < schedule.next_execution_time {
+        return Err(SavingsError::InvalidTimestamp);
+    }
+
+    // Carve the keeper reward out of the scheduled amount
+    let reward = match keeper {
+        Some(_) => calculate_fee(schedule.amount, get_keeper_reward_rate(env))?,
+        None => 0,
+    };
+    let deposit_amount = schedule
+        .amount
+        .checked_sub(reward)
+        .ok_or(SavingsError::Underflow)?;
+
+    // Perform Flexi deposit
+    flexi::flexi_deposit(env.clone(), schedule.user.clone(), deposit_amount)?;
+
+    if let Some(keeper) = keeper {
+        if reward > 0 {
+            let reward_key = AutoSaveKey::KeeperRewards(keeper.clone());
+            let updated = get_keeper_rewards(env, keeper)
+                .checked_add(reward)
+                .ok_or(SavingsError::Overflow)?;
+            env.storage().persistent().set(&reward_key, &updated);
+            events::emit_keeper_rewarded(env, keeper.clone(), schedule_id, reward);
+        }
+    }
+
+    // Update next execution time
+    schedule.next_execution_time = schedule
+        .next_execution_time
+        .checked_add(schedule.interval_seconds)
+        .ok_or(SavingsError::Overflow)?;
+
+    // Save updated schedule
+    env.storage()
+        .persistent()
+        .set(&DataKey::AutoSave(schedule_id), &schedule);
+
+    // Extend TTL on execution (active schedule gets full extension)
+    ttl::extend_autosave_ttl(env, schedule_id);
+
+    Ok(reward)
+}
+
 fn get_next_schedule_id(env: &Env) -> u64 {
     let counter_key = DataKey::NextAutoSaveId;
     let id = env.storage().persistent().get(&counter_key).unwrap_or(1);
@@ -284,6 +807,22 @@ fn increment_next_schedule_id(env: &Env) {
     ttl::extend_counter_ttl(env, &counter_key);
 }
 
+/// First slot on the schedule's cadence at or after `now`, so runs missed
+/// while it was not running are skipped rather than replayed
+fn next_run_at_or_after(schedule: &AutoSave, now: u64) -> Result<u64, SavingsError> {
+    if schedule.next_execution_time >= now {
+        return Ok(schedule.next_execution_time);
+    }
+    let missed = (now - schedule.next_execution_time).div_ceil(schedule.interval_seconds);
+    let skip = missed
+        .checked_mul(schedule.interval_seconds)
+        .ok_or(SavingsError::Overflow)?;
+    schedule
+        .next_execution_time
+        .checked_add(skip)
+        .ok_or(SavingsError::Overflow)
+}
+
 fn add_schedule_to_user(env: &Env, user: &Address, schedule_id: u64) {
     let key = DataKey::UserAutoSaves(user.clone());
     let mut schedules: Vec<u64> = env
@@ -292,9 +831,42 @@ fn add_schedule_to_user(env: &Env, user: &Address, schedule_id: u64) {
         .get(&key)
         .unwrap_or(Vec::new(env));
 
+    // Schedules cancelled before unlinking was added may still be listed
+    if schedules.contains(schedule_id) {
+        return;
+    }
     schedules.push_back(schedule_id);
     env.storage().persistent().set(&key, &schedules);
 
     // Extend TTL on list update
     ttl::extend_user_plan_list_ttl(env, &key);
 }
+
+fn remove_schedule_from_user(env: &Env, user: &Address, schedule_id: u64) {
+    let key = DataKey::UserAutoSaves(user.clone());
+    let mut schedules: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+
+    if let Some(index) = schedules.first_index_of(schedule_id) {
+        schedules.remove(index);
+        env.storage().persistent().set(&key, &schedules);
+        ttl::extend_user_plan_list_ttl(env, &key);
+    }
+}
+
+fn add_schedule_to_global_index(env: &Env, schedule_id: u64) {
+    let key = DataKey::AllAutoSaveIds;
+    let mut schedules: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+
+    schedules.push_back(schedule_id);
+    env.storage().persistent().set(&key, &schedules);
+
+    ttl::extend_user_plan_list_ttl(env, &key);
+}
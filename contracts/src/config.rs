@@ -1,10 +1,26 @@
 use crate::errors::SavingsError;
+use crate::stats::PlanKind;
 use crate::storage_types::DataKey;
-use soroban_sdk::{contracttype, symbol_short, Address, Env};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
 
 /// Maximum fee in basis points (100% = 10000 bps)
 const MAX_FEE_BPS: u32 = 10_000;
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigKey {
+    /// Asset contract savers deposit, once set through governance
+    Token,
+    /// Set once by `emergency_shutdown`; never cleared
+    Shutdown,
+    /// Whether new plans of a type may be created; absent means enabled
+    PlanTypeEnabled(PlanKind),
+    /// Extra leading topic on every event, identifying this deployment
+    EventNamespace,
+    /// Asset lock interest is paid in, when it differs from the deposit token
+    RewardToken,
+}
+
 /// Global configuration for the Nestera protocol.
 ///
 /// This struct is assembled from individual storage keys rather than
@@ -87,8 +103,7 @@ pub fn initialize_config(
         .instance()
         .set(&DataKey::ConfigInitialized, &true);
 
-    env.events()
-        .publish((symbol_short!("cfg_init"),), protocol_fee_bps);
+    crate::events::publish(env, (symbol_short!("cfg_init"),), protocol_fee_bps);
 
     Ok(())
 }
@@ -153,8 +168,7 @@ pub fn set_treasury(env: &Env, admin: Address, new_treasury: Address) -> Result<
         .instance()
         .set(&DataKey::Treasury, &new_treasury);
 
-    env.events()
-        .publish((symbol_short!("set_trs"),), new_treasury);
+    crate::events::publish(env, (symbol_short!("set_trs"),), new_treasury);
 
     Ok(())
 }
@@ -180,8 +194,7 @@ pub fn set_protocol_fee(env: &Env, admin: Address, new_fee_bps: u32) -> Result<(
         .instance()
         .set(&DataKey::ProtocolFeeBps, &new_fee_bps);
 
-    env.events()
-        .publish((symbol_short!("set_fee"),), new_fee_bps);
+    crate::events::publish(env, (symbol_short!("set_fee"),), new_fee_bps);
 
     Ok(())
 }
@@ -196,10 +209,11 @@ pub fn set_protocol_fee(env: &Env, admin: Address, new_fee_bps: u32) -> Result<(
 /// * `SavingsError::Unauthorized` - If caller is not the admin
 pub fn pause_contract(env: &Env, admin: Address) -> Result<(), SavingsError> {
     require_admin(env, &admin)?;
+    crate::multisig::ensure_single_admin_allowed(env)?;
 
     env.storage().persistent().set(&DataKey::Paused, &true);
 
-    env.events().publish((symbol_short!("pause"),), admin);
+    crate::events::publish(env, (symbol_short!("pause"),), admin);
 
     Ok(())
 }
@@ -214,10 +228,11 @@ pub fn pause_contract(env: &Env, admin: Address) -> Result<(), SavingsError> {
 /// * `SavingsError::Unauthorized` - If caller is not the admin
 pub fn unpause_contract(env: &Env, admin: Address) -> Result<(), SavingsError> {
     require_admin(env, &admin)?;
+    crate::multisig::ensure_single_admin_allowed(env)?;
 
     env.storage().persistent().set(&DataKey::Paused, &false);
 
-    env.events().publish((symbol_short!("unpause"),), admin);
+    crate::events::publish(env, (symbol_short!("unpause"),), admin);
 
     Ok(())
 }
@@ -241,3 +256,158 @@ pub fn require_not_paused(env: &Env) -> Result<(), SavingsError> {
         Ok(())
     }
 }
+
+/// Shuts the protocol down for good.
+///
+/// Unlike a pause this cannot be reverted. Every create and deposit path is
+/// rejected with `ContractShutdown`, while withdrawals stay open even if the
+/// contract is paused, and locks are treated as matured so users can recover
+/// their principal plus interest accrued so far.
+///
+/// Once admin multisig is configured this goes through
+/// `ProposalAction::EmergencyShutdown` instead, like pausing.
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin, or multisig
+///   confirmation is required
+/// * `SavingsError::ContractShutdown` - If already shut down
+pub fn emergency_shutdown(env: &Env, admin: Address) -> Result<(), SavingsError> {
+    require_admin(env, &admin)?;
+    crate::multisig::ensure_single_admin_allowed(env)?;
+
+    shut_down(env)
+}
+
+/// Sets the permanent shutdown flag; callers have already checked authority
+pub(crate) fn shut_down(env: &Env) -> Result<(), SavingsError> {
+    if is_shutdown(env) {
+        return Err(SavingsError::ContractShutdown);
+    }
+    env.storage().persistent().set(&ConfigKey::Shutdown, &true);
+
+    crate::events::publish(env, (symbol_short!("shutdown"),), ());
+
+    Ok(())
+}
+
+/// Whether `emergency_shutdown` has been called
+pub fn is_shutdown(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&ConfigKey::Shutdown)
+        .unwrap_or(false)
+}
+
+// ========== Plan Type Rollout ==========
+
+/// Enables or disables creation of new plans of one type.
+///
+/// Existing plans of a disabled type keep working; only creation is blocked.
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin
+pub fn set_plan_type_enabled(
+    env: &Env,
+    admin: Address,
+    kind: PlanKind,
+    enabled: bool,
+) -> Result<(), SavingsError> {
+    require_admin(env, &admin)?;
+
+    env.storage()
+        .instance()
+        .set(&ConfigKey::PlanTypeEnabled(kind), &enabled);
+    Ok(())
+}
+
+/// Whether new plans of `kind` may be created
+pub fn is_plan_type_enabled(env: &Env, kind: PlanKind) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::PlanTypeEnabled(kind))
+        .unwrap_or(true)
+}
+
+/// Fails with `PlanTypeDisabled` when creation of `kind` is switched off
+pub(crate) fn ensure_plan_type_enabled(env: &Env, kind: PlanKind) -> Result<(), SavingsError> {
+    if !is_plan_type_enabled(env, kind) {
+        return Err(SavingsError::PlanTypeDisabled);
+    }
+    Ok(())
+}
+
+// ========== Event Namespace ==========
+
+/// Sets the symbol prepended to every event's topics. Admin only, and only
+/// once, so a deployment's events never change shape after indexing starts.
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin
+/// * `SavingsError::ConfigAlreadyInitialized` - If a namespace is already set
+pub fn init_event_namespace(
+    env: &Env,
+    admin: Address,
+    namespace: Symbol,
+) -> Result<(), SavingsError> {
+    require_admin(env, &admin)?;
+
+    if get_event_namespace(env).is_some() {
+        return Err(SavingsError::ConfigAlreadyInitialized);
+    }
+    env.storage()
+        .instance()
+        .set(&ConfigKey::EventNamespace, &namespace);
+    Ok(())
+}
+
+/// The deployment's event namespace, if one was set
+pub fn get_event_namespace(env: &Env) -> Option<Symbol> {
+    env.storage().instance().get(&ConfigKey::EventNamespace)
+}
+
+// ========== Token ==========
+
+/// Returns the configured asset contract, if any
+pub fn get_token(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&ConfigKey::Token)
+}
+
+/// Sets the asset lock interest is transferred in at withdrawal (admin only).
+/// Only the interest leg moves tokens; principal stays ledger-only. `None`
+/// stops the transfer and leaves interest ledger-only too.
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin
+pub fn set_reward_token(
+    env: &Env,
+    admin: Address,
+    reward_token: Option<Address>,
+) -> Result<(), SavingsError> {
+    require_admin(env, &admin)?;
+
+    match reward_token {
+        Some(token) => env
+            .storage()
+            .instance()
+            .set(&ConfigKey::RewardToken, &token),
+        None => env.storage().instance().remove(&ConfigKey::RewardToken),
+    }
+    Ok(())
+}
+
+/// The asset lock interest is paid in, if it differs from the deposit token
+pub fn get_reward_token(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&ConfigKey::RewardToken)
+}
+
+/// Points the protocol at a new asset contract.
+///
+/// Refused while any lock is open, since its principal is held in the old
+/// asset and would be stranded.
+pub(crate) fn set_token(env: &Env, token: &Address) -> Result<(), SavingsError> {
+    if crate::stats::get_plan_type_stats(env).lock.count > 0 {
+        return Err(SavingsError::PlanLocked);
+    }
+    env.storage().instance().set(&ConfigKey::Token, token);
+    Ok(())
+}
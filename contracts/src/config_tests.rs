@@ -1,6 +1,12 @@
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, InvokeError};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, BytesN, Env, IntoVal, InvokeError, Symbol,
+};
 
-use crate::{NesteraContract, NesteraContractClient, SavingsError};
+use crate::{
+    LockStatusFilter, NesteraContract, NesteraContractClient, PlanKind, PlanType, SavingsError,
+};
 
 // ========== Test Helpers ==========
 
@@ -443,3 +449,210 @@ fn test_full_config_lifecycle() {
     client.unpause_contract(&admin);
     assert!(!client.get_config().paused);
 }
+
+// ========== emergency_shutdown Tests ==========
+
+#[test]
+fn test_emergency_shutdown_blocks_creation() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    client.emergency_shutdown(&admin);
+    assert!(client.is_shutdown());
+
+    assert_savings_error(
+        client.try_deposit_flexi(&user, &100).unwrap_err(),
+        SavingsError::ContractShutdown,
+    );
+    assert_savings_error(
+        client
+            .try_create_autosave(&user, &100, &3600, &1000)
+            .unwrap_err(),
+        SavingsError::ContractShutdown,
+    );
+    assert!(client.try_create_lock_save(&user, &1_000, &86_400).is_err());
+}
+
+#[test]
+fn test_create_autosave_rejected_while_paused() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    client.pause_contract(&admin);
+
+    assert_savings_error(
+        client
+            .try_create_autosave(&user, &100, &3600, &1000)
+            .unwrap_err(),
+        SavingsError::ContractPaused,
+    );
+    assert_savings_error(
+        client
+            .try_create_autosave_now(&user, &100, &3600)
+            .unwrap_err(),
+        SavingsError::ContractPaused,
+    );
+}
+
+#[test]
+fn test_emergency_shutdown_makes_locks_withdrawable() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    client.deposit_flexi(&user, &500);
+    let lock_id = client.create_lock_save(&user, &1_000, &(365 * 86_400));
+    assert!(!client.check_matured_lock(&lock_id));
+
+    client.emergency_shutdown(&admin);
+    // Withdrawals stay open even if the contract is paused as well
+    client.pause_contract(&admin);
+
+    assert!(client.check_matured_lock(&lock_id));
+    assert_eq!(
+        client.get_withdrawable_locks(&user),
+        soroban_sdk::vec![&env, lock_id]
+    );
+    assert_eq!(
+        client.get_user_locks_by_status(&user, &LockStatusFilter::Matured),
+        soroban_sdk::vec![&env, lock_id]
+    );
+    assert_eq!(client.withdraw_lock_save(&user, &lock_id), 1_000);
+    assert!(client.try_withdraw_flexi(&user, &500).is_ok());
+}
+
+#[test]
+fn test_emergency_shutdown_is_permanent() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    assert_savings_error(
+        client.try_emergency_shutdown(&user).unwrap_err(),
+        SavingsError::Unauthorized,
+    );
+
+    client.emergency_shutdown(&admin);
+    assert_savings_error(
+        client.try_emergency_shutdown(&admin).unwrap_err(),
+        SavingsError::ContractShutdown,
+    );
+
+    client.pause_contract(&admin);
+    client.unpause_contract(&admin);
+    assert!(client.is_shutdown());
+    assert_savings_error(
+        client.try_deposit_flexi(&user, &100).unwrap_err(),
+        SavingsError::ContractShutdown,
+    );
+}
+
+// ========== Plan Type Enablement Tests ==========
+
+#[test]
+fn test_disabled_plan_type_blocks_only_that_type() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    assert!(client.is_plan_type_enabled(&PlanKind::Lock));
+
+    client.set_plan_type_enabled(&admin, &PlanKind::Lock, &false);
+    assert!(!client.is_plan_type_enabled(&PlanKind::Lock));
+    assert!(client.is_plan_type_enabled(&PlanKind::Flexi));
+
+    assert_savings_error(
+        client
+            .try_create_lock_save(&user, &1_000, &86_400)
+            .unwrap_err(),
+        SavingsError::PlanTypeDisabled,
+    );
+    assert_savings_error(
+        client
+            .try_create_savings_plan(&user, &PlanType::Lock(86_400), &1_000)
+            .unwrap_err(),
+        SavingsError::PlanTypeDisabled,
+    );
+    assert_savings_error(
+        client
+            .try_create_vesting_lock(&user, &1_000, &0, &86_400)
+            .unwrap_err(),
+        SavingsError::PlanTypeDisabled,
+    );
+    assert!(client.try_deposit_flexi(&user, &500).is_ok());
+
+    client.set_plan_type_enabled(&admin, &PlanKind::Lock, &true);
+    assert!(client.try_create_lock_save(&user, &1_000, &86_400).is_ok());
+}
+
+#[test]
+fn test_non_admin_cannot_disable_plan_type() {
+    let (env, client, _admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    assert_savings_error(
+        client
+            .try_set_plan_type_enabled(&user, &PlanKind::Flexi, &false)
+            .unwrap_err(),
+        SavingsError::Unauthorized,
+    );
+    assert!(client.is_plan_type_enabled(&PlanKind::Flexi));
+}
+
+// ========== Event Namespace Tests ==========
+
+#[test]
+fn test_events_carry_namespace_topic_when_configured() {
+    let (env, client, admin) = setup();
+
+    env.mock_all_auths();
+    client.pause_contract(&admin);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(topics.len(), 1);
+    let first: Symbol = topics.get(0).unwrap().into_val(&env);
+    assert_eq!(first, symbol_short!("pause"));
+
+    let namespace = Symbol::new(&env, "staging");
+    client.init_event_namespace(&admin, &namespace);
+    assert_eq!(client.get_event_namespace(), Some(namespace.clone()));
+
+    client.unpause_contract(&admin);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(topics.len(), 2);
+    let first: Symbol = topics.get(0).unwrap().into_val(&env);
+    let second: Symbol = topics.get(1).unwrap().into_val(&env);
+    assert_eq!(first, namespace);
+    assert_eq!(second, symbol_short!("unpause"));
+}
+
+#[test]
+fn test_event_namespace_set_once_by_admin() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    assert_eq!(client.get_event_namespace(), None);
+    assert_savings_error(
+        client
+            .try_init_event_namespace(&user, &symbol_short!("prod"))
+            .unwrap_err(),
+        SavingsError::Unauthorized,
+    );
+
+    client.init_event_namespace(&admin, &symbol_short!("prod"));
+    assert_savings_error(
+        client
+            .try_init_event_namespace(&admin, &symbol_short!("staging"))
+            .unwrap_err(),
+        SavingsError::ConfigAlreadyInitialized,
+    );
+    assert_eq!(client.get_event_namespace(), Some(symbol_short!("prod")));
+}
@@ -114,6 +114,12 @@ pub enum SavingsError {
     /// This may apply to time-limited offers or expiring opportunities.
     TooLate = 52,
 
+    /// Returned when a duration or interval is zero or otherwise unusable.
+    ///
+    /// This occurs when:
+    /// - An AutoSave schedule is given a zero interval
+    InvalidDuration = 53,
+
     // ========== Interest and Yield Errors (60-69) ==========
     /// Returned when the specified interest rate is invalid.
     ///
@@ -228,6 +234,29 @@ pub enum SavingsError {
 
     /// Returned when attempting to register a strategy that already exists.
     StrategyAlreadyRegistered = 96,
+
+    /// Returned when an address creates a proposal before its cooldown window has elapsed.
+    ProposalCooldownActive = 97,
+
+    /// Returned when a proposal without any supporting votes is executed.
+    QuorumNotReached = 98,
+
+    /// Returned when a proposal description is empty or longer than allowed.
+    InvalidDescription = 99,
+
+    /// Returned by create and deposit paths after an emergency shutdown.
+    ///
+    /// Shutdown is permanent; only withdrawals remain available.
+    ContractShutdown = 100,
+
+    /// Returned when creating a plan of a type the admin has disabled.
+    PlanTypeDisabled = 101,
+
+    /// Returned when a loan would exceed the allowed share of a lock's principal.
+    LoanLimitExceeded = 102,
+
+    /// Returned when a query would return more entries than allowed; use the paged variant.
+    ResultTooLarge = 103,
 }
 
 #[cfg(test)]
@@ -255,6 +284,7 @@ mod tests {
             SavingsError::InvalidTimestamp as u32,
             SavingsError::TooEarly as u32,
             SavingsError::TooLate as u32,
+            SavingsError::InvalidDuration as u32,
             SavingsError::InvalidInterestRate as u32,
             SavingsError::YieldCalculationError as u32,
             SavingsError::GroupFull as u32,
@@ -276,6 +306,13 @@ mod tests {
             SavingsError::StrategyNotFound as u32,
             SavingsError::StrategyAlreadyRegistered as u32,
             SavingsError::StrategyDisabled as u32,
+            SavingsError::ProposalCooldownActive as u32,
+            SavingsError::QuorumNotReached as u32,
+            SavingsError::InvalidDescription as u32,
+            SavingsError::ContractShutdown as u32,
+            SavingsError::PlanTypeDisabled as u32,
+            SavingsError::LoanLimitExceeded as u32,
+            SavingsError::ResultTooLarge as u32,
         ];
 
         let mut sorted = errors.clone();
@@ -0,0 +1,372 @@
+//! Event schema for the savings contract.
+//!
+//! Every event the savings modules publish is built here, so the topics and
+//! data layout documented on each helper are the contract off-chain indexers
+//! can rely on. Governance events live in `governance_events` and rewards
+//! events in `rewards::events`; the governance helpers are re-exported here so
+//! this module is the single entry point.
+
+use soroban_sdk::{events::Topics, symbol_short, Address, Env, IntoVal, Symbol, Val, Vec};
+
+pub use crate::governance_events::{
+    emit_proposal_canceled, emit_proposal_created, emit_proposal_executed, emit_proposal_queued,
+    emit_vote_cast,
+};
+
+// ========== Publishing ==========
+
+/// Publishes an event, adding the deployment's event namespace as the first
+/// topic when one is configured.
+///
+/// Every event the contract emits goes through here, so indexers watching
+/// several deployments can tell them apart. Without a namespace the topics
+/// are published unchanged.
+pub fn publish<T, D>(env: &Env, topics: T, data: D)
+where
+    T: Topics,
+    D: IntoVal<Env, Val>,
+{
+    match crate::config::get_event_namespace(env) {
+        Some(namespace) => {
+            let mut namespaced: Vec<Val> = Vec::new(env);
+            namespaced.push_back(namespace.into_val(env));
+            namespaced.append(&topics.into_val(env));
+            env.events().publish(namespaced, data);
+        }
+        None => env.events().publish(topics, data),
+    }
+}
+
+// ========== Deposits ==========
+
+/// Topics: `("receipt", user, seq)` — Data: `amount: i128`
+///
+/// `seq` is the contract-wide deposit sequence number.
+pub fn emit_deposit_receipt(env: &Env, user: Address, seq: u64, amount: i128) {
+    publish(env, (symbol_short!("receipt"), user, seq), amount);
+}
+
+/// Topics: `("rcpt_void", user, seq)` — Data: `amount: i128`
+///
+/// The deposit behind receipt `seq` was refunded before it took effect.
+pub fn emit_deposit_receipt_voided(env: &Env, user: Address, seq: u64, amount: i128) {
+    publish(env, (symbol_short!("rcpt_void"), user, seq), amount);
+}
+
+// ========== Lock Save ==========
+
+/// Topics: `("lock_new", owner, lock_id)` — Data: `amount: i128`
+pub fn emit_lock_created(env: &Env, owner: Address, lock_id: u64, amount: i128) {
+    publish(env, (symbol_short!("lock_new"), owner, lock_id), amount);
+}
+
+/// Topics: `("withdraw", owner, lock_id)` — Data: `principal + interest: i128`
+pub fn emit_lock_withdrawn(env: &Env, owner: Address, lock_id: u64, total: i128) {
+    publish(env, (symbol_short!("withdraw"), owner, lock_id), total);
+}
+
+/// Topics: `("lock_cncl", owner, lock_id)` — Data: `refunded principal: i128`
+pub fn emit_lock_canceled(env: &Env, owner: Address, lock_id: u64, refund: i128) {
+    publish(env, (symbol_short!("lock_cncl"), owner, lock_id), refund);
+}
+
+/// Topics: `("lock_adj", owner, lock_id)` — Data: `new maturity_time: u64`
+pub fn emit_lock_adjusted(env: &Env, owner: Address, lock_id: u64, maturity_time: u64) {
+    publish(
+        env,
+        (symbol_short!("lock_adj"), owner, lock_id),
+        maturity_time,
+    );
+}
+
+/// Topics: `("lock_splt", owner, lock_id)` — Data: `(new_lock_id: u64, amount: i128)`
+pub fn emit_lock_split(env: &Env, owner: Address, lock_id: u64, new_lock_id: u64, amount: i128) {
+    publish(
+        env,
+        (symbol_short!("lock_splt"), owner, lock_id),
+        (new_lock_id, amount),
+    );
+}
+
+/// Topics: `("lock_mrg", owner, new_lock_id)` — Data: `(lock_id_a: u64, lock_id_b: u64)`
+pub fn emit_lock_merged(
+    env: &Env,
+    owner: Address,
+    new_lock_id: u64,
+    lock_id_a: u64,
+    lock_id_b: u64,
+) {
+    publish(
+        env,
+        (symbol_short!("lock_mrg"), owner, new_lock_id),
+        (lock_id_a, lock_id_b),
+    );
+}
+
+/// Topics: `("lock_prin", owner, lock_id)` — Data: `principal: i128`
+pub fn emit_lock_principal_paid(env: &Env, owner: Address, lock_id: u64, principal: i128) {
+    publish(env, (symbol_short!("lock_prin"), owner, lock_id), principal);
+}
+
+/// Topics: `("lock_int", owner, lock_id)` — Data: `interest: i128`
+pub fn emit_lock_interest_paid(env: &Env, owner: Address, lock_id: u64, interest: i128) {
+    publish(env, (symbol_short!("lock_int"), owner, lock_id), interest);
+}
+
+/// Topics: `("donate", charity, lock_id)` — Data: `donated interest: i128`
+pub fn emit_lock_donation(env: &Env, charity: Address, lock_id: u64, amount: i128) {
+    publish(env, (symbol_short!("donate"), charity, lock_id), amount);
+}
+
+/// Topics: `("vest_new", owner, lock_id)` — Data: `amount: i128`
+pub fn emit_vesting_created(env: &Env, owner: Address, lock_id: u64, amount: i128) {
+    publish(env, (symbol_short!("vest_new"), owner, lock_id), amount);
+}
+
+/// Topics: `("vest_clm", owner, lock_id)` — Data: `claimed: i128`
+pub fn emit_vesting_claimed(env: &Env, owner: Address, lock_id: u64, claimed: i128) {
+    publish(env, (symbol_short!("vest_clm"), owner, lock_id), claimed);
+}
+
+// ========== Flexi Save ==========
+
+/// Topics: `("dep_for", payer, beneficiary)` — Data: `amount: i128`
+pub fn emit_flexi_deposit_for(env: &Env, payer: Address, beneficiary: Address, amount: i128) {
+    publish(env, (symbol_short!("dep_for"), payer, beneficiary), amount);
+}
+
+/// Topics: `("dep_fee", recipient)` — Data: `fee: i128`
+pub fn emit_flexi_deposit_fee(env: &Env, recipient: Address, fee: i128) {
+    publish(env, (symbol_short!("dep_fee"), recipient), fee);
+}
+
+/// Topics: `("wth_fee", recipient)` — Data: `fee: i128`
+pub fn emit_flexi_withdraw_fee(env: &Env, recipient: Address, fee: i128) {
+    publish(env, (symbol_short!("wth_fee"), recipient), fee);
+}
+
+/// Topics: `("flx_fee", user)` — Data: `fee: i128` charged at a negative flexi rate
+pub fn emit_flexi_custody_fee(env: &Env, user: Address, fee: i128) {
+    publish(env, (symbol_short!("flx_fee"), user), fee);
+}
+
+// ========== Goal Save ==========
+
+/// Topics: `("gdep_fee", recipient, goal_id)` — Data: `fee: i128`
+pub fn emit_goal_deposit_fee(env: &Env, recipient: Address, goal_id: u64, fee: i128) {
+    publish(env, (symbol_short!("gdep_fee"), recipient, goal_id), fee);
+}
+
+/// Topics: `("gwth_fee", recipient, goal_id)` — Data: `fee: i128`
+pub fn emit_goal_withdraw_fee(env: &Env, recipient: Address, goal_id: u64, fee: i128) {
+    publish(env, (symbol_short!("gwth_fee"), recipient, goal_id), fee);
+}
+
+/// Topics: `("brk_fee", recipient, goal_id)` — Data: `fee: i128`
+pub fn emit_goal_break_fee(env: &Env, recipient: Address, goal_id: u64, fee: i128) {
+    publish(env, (symbol_short!("brk_fee"), recipient, goal_id), fee);
+}
+
+/// Topics: `("goal_brk", owner, goal_id)` — Data: `net_amount: i128`
+pub fn emit_goal_broken(env: &Env, owner: Address, goal_id: u64, net_amount: i128) {
+    publish(env, (symbol_short!("goal_brk"), owner, goal_id), net_amount);
+}
+
+/// Topics: `("gshr_paid", contributor, goal_id)` — Data: `payout: i128`
+pub fn emit_goal_share_paid(env: &Env, contributor: Address, goal_id: u64, payout: i128) {
+    publish(
+        env,
+        (symbol_short!("gshr_paid"), contributor, goal_id),
+        payout,
+    );
+}
+
+/// Topics: `("remind", owner, goal_id)` — Data: `(current_amount: i128, target_amount: i128)`
+pub fn emit_goal_reminder(
+    env: &Env,
+    owner: Address,
+    goal_id: u64,
+    current_amount: i128,
+    target_amount: i128,
+) {
+    publish(
+        env,
+        (symbol_short!("remind"), owner, goal_id),
+        (current_amount, target_amount),
+    );
+}
+
+// ========== Group Save ==========
+
+/// Topics: `("grp_new", creator)` — Data: `group_id: u64`
+pub fn emit_group_created(env: &Env, creator: Address, group_id: u64) {
+    publish(env, (symbol_short!("grp_new"), creator), group_id);
+}
+
+/// Topics: `("grp_join", member)` — Data: `group_id: u64`
+pub fn emit_group_joined(env: &Env, member: Address, group_id: u64) {
+    publish(env, (symbol_short!("grp_join"), member), group_id);
+}
+
+/// Topics: `("grp_cont", member, group_id)` — Data: `amount: i128`
+pub fn emit_group_contribution(env: &Env, member: Address, group_id: u64, amount: i128) {
+    publish(env, (symbol_short!("grp_cont"), member, group_id), amount);
+}
+
+/// Topics: `("grp_leave", member, group_id)` — Data: `refunded: i128`
+pub fn emit_group_left(env: &Env, member: Address, group_id: u64, refunded: i128) {
+    publish(
+        env,
+        (symbol_short!("grp_leave"), member, group_id),
+        refunded,
+    );
+}
+
+// ========== AutoSave ==========
+
+/// Topics: `("keep_rwd", keeper, schedule_id)` — Data: `reward: i128`
+pub fn emit_keeper_rewarded(env: &Env, keeper: Address, schedule_id: u64, reward: i128) {
+    publish(
+        env,
+        (symbol_short!("keep_rwd"), keeper, schedule_id),
+        reward,
+    );
+}
+
+// ========== Batches ==========
+
+/// Most items a batch entry point processes in one call.
+///
+/// Every processed item publishes its own events (withdrawal, deposit
+/// receipt, rewards...), so bounding the batch keeps a single invocation
+/// within the network's per-transaction contract event size limit. Larger
+/// workloads are split across several calls.
+pub const MAX_BATCH_ITEMS: u32 = 25;
+
+/// Topics: `("batch", kind)` — Data: `(processed: u32, succeeded: u32)`
+///
+/// Published once at the end of a batch call, after all per-item events.
+/// `kind` names the batch, e.g. `lock_wd` or `autosave`.
+pub fn emit_batch_summary(env: &Env, kind: Symbol, processed: u32, succeeded: u32) {
+    publish(env, (symbol_short!("batch"), kind), (processed, succeeded));
+}
+
+// ========== Reserve ==========
+
+/// Topics: `("lowresv",)` — Data: `reserve: i128`
+///
+/// Emitted once each time the interest reserve falls below the configured
+/// warning threshold.
+pub fn emit_low_reserve(env: &Env, reserve: i128) {
+    publish(env, (symbol_short!("lowresv"),), reserve);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance_events::VoteCast;
+    use crate::NesteraContract;
+    use soroban_sdk::{
+        testutils::{Address as _, Events},
+        FromVal, IntoVal, Val, Vec,
+    };
+
+    fn last_event(env: &Env, emit: impl FnOnce()) -> (Vec<Val>, Val) {
+        let contract_id = env.register(NesteraContract, ());
+        env.as_contract(&contract_id, emit);
+        let (event_contract, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(event_contract, contract_id);
+        (topics, data)
+    }
+
+    #[test]
+    fn test_lock_event_shapes() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+
+        let (topics, data) = last_event(&env, || emit_lock_created(&env, owner.clone(), 7, 1_000));
+        assert_eq!(
+            topics,
+            (symbol_short!("lock_new"), owner.clone(), 7u64).into_val(&env)
+        );
+        assert_eq!(i128::from_val(&env, &data), 1_000);
+
+        let (topics, data) =
+            last_event(&env, || emit_lock_withdrawn(&env, owner.clone(), 7, 1_050));
+        assert_eq!(
+            topics,
+            (symbol_short!("withdraw"), owner.clone(), 7u64).into_val(&env)
+        );
+        assert_eq!(i128::from_val(&env, &data), 1_050);
+
+        let (topics, data) =
+            last_event(&env, || emit_lock_interest_paid(&env, owner.clone(), 7, 50));
+        assert_eq!(
+            topics,
+            (symbol_short!("lock_int"), owner.clone(), 7u64).into_val(&env)
+        );
+        assert_eq!(i128::from_val(&env, &data), 50);
+    }
+
+    #[test]
+    fn test_flexi_and_goal_event_shapes() {
+        let env = Env::default();
+        let payer = Address::generate(&env);
+        let beneficiary = Address::generate(&env);
+
+        let (topics, data) = last_event(&env, || {
+            emit_flexi_deposit_for(&env, payer.clone(), beneficiary.clone(), 500)
+        });
+        assert_eq!(
+            topics,
+            (symbol_short!("dep_for"), payer.clone(), beneficiary.clone()).into_val(&env)
+        );
+        assert_eq!(i128::from_val(&env, &data), 500);
+
+        let (topics, data) = last_event(&env, || emit_goal_broken(&env, payer.clone(), 3, 900));
+        assert_eq!(
+            topics,
+            (symbol_short!("goal_brk"), payer.clone(), 3u64).into_val(&env)
+        );
+        assert_eq!(i128::from_val(&env, &data), 900);
+    }
+
+    #[test]
+    fn test_group_event_shapes() {
+        let env = Env::default();
+        let member = Address::generate(&env);
+
+        let (topics, data) = last_event(&env, || emit_group_created(&env, member.clone(), 4));
+        assert_eq!(
+            topics,
+            (symbol_short!("grp_new"), member.clone()).into_val(&env)
+        );
+        assert_eq!(u64::from_val(&env, &data), 4);
+
+        let (topics, data) = last_event(&env, || {
+            emit_group_contribution(&env, member.clone(), 4, 250)
+        });
+        assert_eq!(
+            topics,
+            (symbol_short!("grp_cont"), member.clone(), 4u64).into_val(&env)
+        );
+        assert_eq!(i128::from_val(&env, &data), 250);
+    }
+
+    #[test]
+    fn test_vote_cast_event_shape() {
+        let env = Env::default();
+        let voter = Address::generate(&env);
+
+        let (topics, data) = last_event(&env, || emit_vote_cast(&env, 1, voter.clone(), 1, 300));
+        assert_eq!(
+            topics,
+            (symbol_short!("gov"), symbol_short!("voted"), voter.clone()).into_val(&env)
+        );
+        let event = VoteCast::from_val(&env, &data);
+        assert_eq!(event.proposal_id, 1);
+        assert_eq!(event.voter, voter);
+        assert_eq!(event.vote_type, 1);
+        assert_eq!(event.weight, 300);
+    }
+}
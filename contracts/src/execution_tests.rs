@@ -1,11 +1,11 @@
 #[cfg(test)]
 mod execution_tests {
-    use crate::governance::ProposalAction;
+    use crate::governance::{ProposalAction, VotingPowerSource};
     use crate::rewards::storage_types::RewardsConfig;
-    use crate::{NesteraContract, NesteraContractClient, PlanType};
+    use crate::{NesteraContract, NesteraContractClient, PlanType, SavingsError};
     use soroban_sdk::{
         testutils::{Address as _, Ledger},
-        Address, BytesN, Env, String,
+        Address, BytesN, Env, String, Symbol,
     };
 
     fn setup_contract() -> (Env, NesteraContractClient<'static>, Address) {
@@ -146,7 +146,7 @@ mod execution_tests {
             li.timestamp += 86400 + 1;
         });
 
-        let result = client.try_execute_proposal(&proposal_id);
+        let result = client.try_execute_proposal(&proposal_id, &Address::generate(&env));
         assert!(result.is_ok());
 
         let proposal = client.get_action_proposal(&proposal_id).unwrap();
@@ -156,6 +156,33 @@ mod execution_tests {
         assert_eq!(client.get_flexi_rate(), 500);
     }
 
+    #[test]
+    fn test_execute_proposal_records_executor_and_time() {
+        let (env, client, _admin, proposal_id) = setup_with_voted_proposal();
+        env.mock_all_auths();
+
+        env.ledger().with_mut(|li| li.timestamp += 604800 + 1);
+        client.queue_proposal(&proposal_id);
+        env.ledger().with_mut(|li| li.timestamp += 86400 + 1);
+
+        let result = client.get_proposal_result(&proposal_id);
+        assert_eq!(result.executor, None);
+        assert_eq!(result.executed_at, 0);
+
+        let executor = Address::generate(&env);
+        let executed_at = env.ledger().timestamp();
+        client.execute_proposal(&proposal_id, &executor);
+
+        let result = client.get_proposal_result(&proposal_id);
+        assert_eq!(result.executor, Some(executor.clone()));
+        assert_eq!(result.executed_at, executed_at);
+
+        assert_eq!(
+            client.get_proposal_execution(&proposal_id),
+            Some((executor, executed_at))
+        );
+    }
+
     #[test]
     fn test_execute_proposal_before_timelock() {
         let (env, client, _admin, proposal_id) = setup_with_voted_proposal();
@@ -169,7 +196,7 @@ mod execution_tests {
         client.queue_proposal(&proposal_id);
 
         // Try to execute before timelock
-        let result = client.try_execute_proposal(&proposal_id);
+        let result = client.try_execute_proposal(&proposal_id, &Address::generate(&env));
         assert!(result.is_err());
     }
 
@@ -184,10 +211,44 @@ mod execution_tests {
         });
 
         // Try to execute without queueing
-        let result = client.try_execute_proposal(&proposal_id);
+        let result = client.try_execute_proposal(&proposal_id, &Address::generate(&env));
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_abstain_only_proposal_cannot_execute() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+
+        let creator = Address::generate(&env);
+        client.initialize_user(&creator);
+        let _ = client.create_savings_plan(&creator, &PlanType::Flexi, &1000);
+
+        let description = String::from_str(&env, "Abstain only");
+        let proposal_id = client
+            .try_create_action_proposal(&creator, &description, &ProposalAction::SetFlexiRate(500))
+            .unwrap()
+            .unwrap();
+
+        let voter = Address::generate(&env);
+        client.initialize_user(&voter);
+        let _ = client.create_savings_plan(&voter, &PlanType::Flexi, &3000);
+        client.vote(&proposal_id, &3, &voter);
+
+        env.ledger().with_mut(|li| {
+            li.timestamp += 604800 + 1;
+        });
+
+        assert!(client.try_queue_proposal(&proposal_id).is_err());
+        assert_eq!(
+            client.try_execute_proposal(&proposal_id, &Address::generate(&env)),
+            Err(Ok(SavingsError::QuorumNotReached))
+        );
+        assert_eq!(client.get_flexi_rate(), 0);
+    }
+
     #[test]
     fn test_cannot_queue_twice() {
         let (env, client, _admin, proposal_id) = setup_with_voted_proposal();
@@ -221,9 +282,9 @@ mod execution_tests {
             li.timestamp += 86400 + 1;
         });
 
-        client.execute_proposal(&proposal_id);
+        client.execute_proposal(&proposal_id, &Address::generate(&env));
 
-        let result = client.try_execute_proposal(&proposal_id);
+        let result = client.try_execute_proposal(&proposal_id, &Address::generate(&env));
         assert!(result.is_err());
     }
 
@@ -273,7 +334,7 @@ mod execution_tests {
         });
 
         // Execute
-        client.execute_proposal(&proposal_id);
+        client.execute_proposal(&proposal_id, &Address::generate(&env));
 
         // Verify
         assert_eq!(client.get_flexi_rate(), 750);
@@ -313,8 +374,224 @@ mod execution_tests {
         env.ledger().with_mut(|li| {
             li.timestamp += 86400 + 1;
         });
-        client.execute_proposal(&proposal_id);
+        client.execute_proposal(&proposal_id, &Address::generate(&env));
 
         assert!(client.is_paused());
     }
+
+    fn pass_action_proposal(
+        env: &Env,
+        client: &NesteraContractClient<'static>,
+        admin: &Address,
+        action: ProposalAction,
+    ) -> u64 {
+        env.mock_all_auths();
+        client.init_voting_config(admin, &5000, &604800, &86400, &100, &10_000);
+
+        let creator = Address::generate(env);
+        client.initialize_user(&creator);
+        let _ = client.create_savings_plan(&creator, &PlanType::Flexi, &1000);
+
+        let description = String::from_str(env, "Update lock limits");
+        let proposal_id = client
+            .try_create_action_proposal(&creator, &description, &action)
+            .unwrap()
+            .unwrap();
+
+        let voter = Address::generate(env);
+        client.initialize_user(&voter);
+        let _ = client.create_savings_plan(&voter, &PlanType::Flexi, &5000);
+        client.vote(&proposal_id, &1, &voter);
+
+        env.ledger().with_mut(|li| {
+            li.timestamp += 604800 + 1;
+        });
+        client.queue_proposal(&proposal_id);
+
+        env.ledger().with_mut(|li| {
+            li.timestamp += 86400 + 1;
+        });
+
+        proposal_id
+    }
+
+    #[test]
+    fn test_execute_set_lock_limits_action() {
+        let (env, client, admin) = setup_contract();
+        assert_eq!(client.get_lock_limits(), (0, i128::MAX));
+
+        let proposal_id = pass_action_proposal(
+            &env,
+            &client,
+            &admin,
+            ProposalAction::SetLockLimits(100, 10_000),
+        );
+        client.execute_proposal(&proposal_id, &Address::generate(&env));
+
+        assert_eq!(client.get_lock_limits(), (100, 10_000));
+
+        // Subsequent lock creation respects the new limits
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+        assert!(client.try_create_lock_save(&user, &99, &86400).is_err());
+        assert!(client.try_create_lock_save(&user, &10_001, &86400).is_err());
+        assert!(client.try_create_lock_save(&user, &100, &86400).is_ok());
+        assert!(client.try_create_lock_save(&user, &10_000, &86400).is_ok());
+    }
+
+    #[test]
+    fn test_execute_set_lock_limits_rejects_min_above_max() {
+        let (env, client, admin) = setup_contract();
+
+        let proposal_id = pass_action_proposal(
+            &env,
+            &client,
+            &admin,
+            ProposalAction::SetLockLimits(500, 100),
+        );
+        let result = client.try_execute_proposal(&proposal_id, &Address::generate(&env));
+        assert_eq!(result, Err(Ok(SavingsError::InvalidPlanConfig)));
+
+        assert_eq!(client.get_lock_limits(), (0, i128::MAX));
+    }
+
+    #[test]
+    fn test_execute_set_token_blocked_by_active_locks() {
+        let (env, client, admin) = setup_contract();
+        let new_token = Address::generate(&env);
+
+        let proposal_id =
+            pass_action_proposal(&env, &client, &admin, ProposalAction::SetToken(new_token));
+
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+        client.create_lock_save(&user, &1_000, &86400);
+
+        let result = client.try_execute_proposal(&proposal_id, &Address::generate(&env));
+        assert_eq!(result, Err(Ok(SavingsError::PlanLocked)));
+        assert_eq!(client.get_token(), None);
+    }
+
+    #[test]
+    fn test_execute_set_token_without_active_locks() {
+        let (env, client, admin) = setup_contract();
+        let new_token = Address::generate(&env);
+        assert_eq!(client.get_token(), None);
+
+        let proposal_id = pass_action_proposal(
+            &env,
+            &client,
+            &admin,
+            ProposalAction::SetToken(new_token.clone()),
+        );
+        client.execute_proposal(&proposal_id, &Address::generate(&env));
+
+        assert_eq!(client.get_token(), Some(new_token));
+    }
+
+    #[test]
+    fn test_governance_stats_track_participation() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+        assert_eq!(client.get_governance_stats().total_proposals, 0);
+
+        let creator = Address::generate(&env);
+        let first = client.create_proposal(&creator, &String::from_str(&env, "First"));
+        let second = client.create_proposal(&creator, &String::from_str(&env, "Second"));
+
+        let voter1 = Address::generate(&env);
+        let voter2 = Address::generate(&env);
+        client.initialize_user(&voter1);
+        client.initialize_user(&voter2);
+        let _ = client.create_savings_plan(&voter1, &PlanType::Flexi, &5000);
+        let _ = client.create_savings_plan(&voter2, &PlanType::Flexi, &2000);
+
+        client.vote(&first, &1, &voter1);
+        client.vote(&first, &2, &voter2);
+        // A repeat voter on another proposal is not counted twice
+        client.vote(&second, &3, &voter1);
+
+        let stats = client.get_governance_stats();
+        assert_eq!(stats.total_proposals, 2);
+        assert_eq!(stats.unique_voters, 2);
+        assert_eq!(stats.total_voting_power_cast, 12_000);
+        assert_eq!(stats.executed_proposals, 0);
+
+        env.ledger().with_mut(|li| li.timestamp += 604800 + 1);
+        client.queue_proposal(&first);
+        env.ledger().with_mut(|li| li.timestamp += 86400 + 1);
+        client.execute_proposal(&first, &Address::generate(&env));
+
+        let stats = client.get_governance_stats();
+        assert_eq!(stats.executed_proposals, 1);
+        assert_eq!(stats.total_proposals, 2);
+    }
+
+    #[test]
+    fn test_describe_action_covers_every_variant() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+
+        let token = Address::generate(&env);
+        let cases = [
+            (ProposalAction::SetFlexiRate(300), "flexi_rt", 300, 0, None),
+            (ProposalAction::SetGoalRate(400), "goal_rt", 400, 0, None),
+            (ProposalAction::SetGroupRate(450), "group_rt", 450, 0, None),
+            (
+                ProposalAction::SetLockRate(86_400, 700),
+                "lock_rt",
+                86_400,
+                700,
+                None,
+            ),
+            (
+                ProposalAction::SetLockLimits(100, 9_000),
+                "lock_lim",
+                100,
+                9_000,
+                None,
+            ),
+            (ProposalAction::PauseContract, "pause", 0, 0, None),
+            (ProposalAction::UnpauseContract, "unpause", 0, 0, None),
+            (
+                ProposalAction::SetToken(token.clone()),
+                "set_token",
+                0,
+                0,
+                Some(token.clone()),
+            ),
+            (
+                ProposalAction::SetPowerSource(VotingPowerSource::CurrentLocked),
+                "power_src",
+                2,
+                0,
+                None,
+            ),
+        ];
+
+        for (action, tag, param1, param2, address) in cases {
+            let creator = Address::generate(&env);
+            client.initialize_user(&creator);
+            let _ = client.create_savings_plan(&creator, &PlanType::Flexi, &1000);
+            let proposal_id = client.create_action_proposal(
+                &creator,
+                &String::from_str(&env, "Describe me"),
+                &action,
+            );
+
+            let description = client.describe_action(&proposal_id).unwrap();
+            assert_eq!(description.action_type, Symbol::new(&env, tag));
+            assert_eq!(description.param1, param1);
+            assert_eq!(description.param2, param2);
+            assert_eq!(description.address, address);
+        }
+
+        // Plain proposals and unknown IDs have no action
+        let creator = Address::generate(&env);
+        let plain = client.create_proposal(&creator, &String::from_str(&env, "Plain"));
+        assert_eq!(client.describe_action(&plain), None);
+        assert_eq!(client.describe_action(&999), None);
+    }
 }
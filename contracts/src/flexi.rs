@@ -1,12 +1,25 @@
 // New/Correct
 use crate::calculate_fee;
-use crate::ensure_not_paused;
 use crate::errors::SavingsError;
+use crate::events;
 use crate::invariants;
+use crate::rates;
 use crate::rewards;
-use crate::storage_types::{DataKey, User};
+use crate::stats::{self, PlanKind};
+use crate::storage_types::DataKey;
 use crate::ttl;
-use soroban_sdk::{symbol_short, Address, Env};
+use crate::users;
+use crate::{ensure_not_paused, ensure_withdrawals_allowed};
+use soroban_sdk::{contracttype, token, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FlexiKey {
+    /// Last time flexi interest was applied to the user's balance
+    LastAccrual(Address),
+    /// Smallest deposit accepted into Flexi Save
+    MinDeposit,
+}
 
 /// Handles depositing funds into the Flexi Save pool.
 pub fn flexi_deposit(env: Env, user: Address, amount: i128) -> Result<(), SavingsError> {
@@ -15,10 +28,52 @@ pub fn flexi_deposit(env: Env, user: Address, amount: i128) -> Result<(), Saving
     // 1. Verify the caller is the user
     user.require_auth();
 
+    credit_flexi_deposit(&env, &user, amount)
+}
+
+/// Deposits into `beneficiary`'s Flexi Save balance, funded by `payer`.
+///
+/// Only the payer authorizes; the beneficiary must already be initialized.
+/// Deposit points accrue to the beneficiary, whose balance is credited.
+///
+/// When a deposit token is configured, `amount` is transferred from the
+/// payer to the contract. Without one the deposit is ledger-only, like
+/// `flexi_deposit`.
+pub fn flexi_deposit_for(
+    env: Env,
+    payer: Address,
+    beneficiary: Address,
+    amount: i128,
+) -> Result<(), SavingsError> {
+    ensure_not_paused(&env)?;
+
+    // 1. Verify the caller is the payer
+    payer.require_auth();
+
+    if !users::user_exists(&env, &beneficiary) {
+        return Err(SavingsError::UserNotFound);
+    }
+
+    credit_flexi_deposit(&env, &beneficiary, amount)?;
+
+    if let Some(token) = crate::config::get_token(&env) {
+        token::Client::new(&env, &token).transfer(&payer, &env.current_contract_address(), &amount);
+    }
+
+    events::emit_flexi_deposit_for(&env, payer, beneficiary, amount);
+
+    Ok(())
+}
+
+/// Credits a Flexi deposit to `user`, charging the protocol fee.
+fn credit_flexi_deposit(env: &Env, user: &Address, amount: i128) -> Result<(), SavingsError> {
+    crate::config::ensure_plan_type_enabled(env, PlanKind::Flexi)?;
+
     // 2. Validate the amount
-    if amount <= 0 {
+    if amount <= 0 || amount < get_min_flexi_deposit(env).unwrap_or(0) {
         return Err(SavingsError::InvalidAmount);
     }
+    accrue_flexi(env, user)?;
 
     // 3. Calculate protocol fee
     let fee_bps: u32 = env
@@ -43,23 +98,31 @@ pub fn flexi_deposit(env: Env, user: Address, amount: i128) -> Result<(), Saving
         .persistent()
         .set(&flexi_key, &new_flexi_balance);
 
+    if current_flexi_balance == 0 && new_flexi_balance > 0 {
+        stats::record_plan_opened(env, PlanKind::Flexi, net_amount)?;
+    } else {
+        stats::record_principal_added(env, PlanKind::Flexi, net_amount)?;
+    }
+
     // 5. Sync with the main User struct (Total Balance)
     let user_key = DataKey::User(user.clone());
-    if let Some(mut user_data) = env.storage().persistent().get::<DataKey, User>(&user_key) {
+    if let Some(mut user_data) = users::load_user(env, user) {
         user_data.total_balance = user_data
             .total_balance
             .checked_add(net_amount)
             .ok_or(SavingsError::Overflow)?;
+        user_data.record_activity(env.ledger().timestamp(), true);
         env.storage().persistent().set(&user_key, &user_data);
     } else {
         return Err(SavingsError::UserNotFound);
     }
 
     // Extend TTL on user interaction
-    ttl::extend_user_ttl(&env, &user);
+    ttl::extend_user_ttl(env, user);
 
     // 6. Award deposit points (streak, rewards)
-    rewards::storage::award_deposit_points(&env, user.clone(), amount)?;
+    rewards::storage::award_deposit_points(env, user.clone(), amount)?;
+    stats::issue_deposit_receipt(env, user, amount)?;
 
     // 7. Transfer fee to treasury if fee > 0
     if fee_amount > 0 {
@@ -78,8 +141,7 @@ pub fn flexi_deposit(env: Env, user: Address, amount: i128) -> Result<(), Saving
                 .checked_add(fee_amount)
                 .ok_or(SavingsError::Overflow)?;
             env.storage().persistent().set(&fee_key, &new_fee_balance);
-            env.events()
-                .publish((symbol_short!("dep_fee"), fee_recipient), fee_amount);
+            events::emit_flexi_deposit_fee(env, fee_recipient, fee_amount);
         }
     }
 
@@ -88,7 +150,7 @@ pub fn flexi_deposit(env: Env, user: Address, amount: i128) -> Result<(), Saving
 
 /// Handles withdrawing funds from the Flexi Save pool.
 pub fn flexi_withdraw(env: Env, user: Address, amount: i128) -> Result<(), SavingsError> {
-    ensure_not_paused(&env)?;
+    ensure_withdrawals_allowed(&env)?;
 
     // 1. Verify the caller is the user
     user.require_auth();
@@ -97,6 +159,7 @@ pub fn flexi_withdraw(env: Env, user: Address, amount: i128) -> Result<(), Savin
     if amount <= 0 {
         return Err(SavingsError::InvalidAmount);
     }
+    accrue_flexi(&env, &user)?;
 
     // 1. Fetch the balance first
     let current_balance = get_flexi_balance(&env, user.clone()).unwrap_or(0);
@@ -131,13 +194,20 @@ pub fn flexi_withdraw(env: Env, user: Address, amount: i128) -> Result<(), Savin
         .persistent()
         .set(&flexi_key, &new_flexi_balance);
 
+    if new_flexi_balance == 0 {
+        stats::record_plan_closed(&env, PlanKind::Flexi, amount)?;
+    } else {
+        stats::record_principal_removed(&env, PlanKind::Flexi, amount)?;
+    }
+
     // 5. Sync with the main User struct (Total Balance)
     let user_key = DataKey::User(user.clone());
-    if let Some(mut user_data) = env.storage().persistent().get::<DataKey, User>(&user_key) {
+    if let Some(mut user_data) = users::load_user(&env, &user) {
         user_data.total_balance = user_data
             .total_balance
             .checked_sub(amount)
             .ok_or(SavingsError::Underflow)?;
+        user_data.record_activity(env.ledger().timestamp(), false);
         env.storage().persistent().set(&user_key, &user_data);
     }
 
@@ -161,23 +231,195 @@ pub fn flexi_withdraw(env: Env, user: Address, amount: i128) -> Result<(), Savin
                 .checked_add(fee_amount)
                 .ok_or(SavingsError::Overflow)?;
             env.storage().persistent().set(&fee_key, &new_fee_balance);
-            env.events()
-                .publish((symbol_short!("wth_fee"), fee_recipient), fee_amount);
+            events::emit_flexi_withdraw_fee(&env, fee_recipient, fee_amount);
         }
     }
 
     Ok(())
 }
+/// Sets the smallest accepted Flexi deposit (admin only).
+///
+/// `None` removes the minimum.
+pub fn set_min_flexi_deposit(
+    env: &Env,
+    admin: Address,
+    min: Option<i128>,
+) -> Result<(), SavingsError> {
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(SavingsError::Unauthorized)?;
+    if stored_admin != admin {
+        return Err(SavingsError::Unauthorized);
+    }
+    admin.require_auth();
+
+    let key = FlexiKey::MinDeposit;
+    match min {
+        Some(min) if min < 0 => return Err(SavingsError::InvalidAmount),
+        Some(min) => env.storage().instance().set(&key, &min),
+        None => env.storage().instance().remove(&key),
+    }
+    Ok(())
+}
+
+pub fn get_min_flexi_deposit(env: &Env) -> Option<i128> {
+    env.storage().instance().get(&FlexiKey::MinDeposit)
+}
+
+/// Flexi interest accrued since the user's last accrual, not yet applied
+pub fn pending_flexi_interest(env: &Env, user: &Address) -> i128 {
+    let Some(last) = env
+        .storage()
+        .persistent()
+        .get::<_, u64>(&FlexiKey::LastAccrual(user.clone()))
+    else {
+        return 0;
+    };
+    let balance: i128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::FlexiBalance(user.clone()))
+        .unwrap_or(0);
+    let elapsed = env.ledger().timestamp().saturating_sub(last);
+    rates::calculate_flexi_accrued_interest(env, balance, elapsed)
+}
+
+/// Applies flexi interest accrued since the user's last accrual.
+///
+/// At a negative flexi rate this charges a custody fee instead, flooring the
+/// balance at zero and emitting a `flx_fee` event. Returns the signed amount
+/// applied to the balance.
+pub fn accrue_flexi(env: &Env, user: &Address) -> Result<i128, SavingsError> {
+    let now = env.ledger().timestamp();
+    let accrual_key = FlexiKey::LastAccrual(user.clone());
+    let last: Option<u64> = env.storage().persistent().get(&accrual_key);
+    env.storage().persistent().set(&accrual_key, &now);
+
+    let Some(last) = last else {
+        return Ok(0);
+    };
+
+    let flexi_key = DataKey::FlexiBalance(user.clone());
+    let balance: i128 = env.storage().persistent().get(&flexi_key).unwrap_or(0);
+    let accrued = rates::calculate_flexi_accrued_interest(env, balance, now.saturating_sub(last));
+    if accrued == 0 {
+        return Ok(0);
+    }
+
+    let new_balance = balance
+        .checked_add(accrued)
+        .ok_or(SavingsError::Overflow)?
+        .max(0);
+    let applied = new_balance - balance;
+    env.storage().persistent().set(&flexi_key, &new_balance);
+
+    if applied > 0 {
+        stats::record_principal_added(env, PlanKind::Flexi, applied)?;
+        crate::penalty::fund_interest_payout(env, applied)?;
+    } else if new_balance == 0 {
+        stats::record_plan_closed(env, PlanKind::Flexi, balance)?;
+    } else {
+        stats::record_principal_removed(env, PlanKind::Flexi, -applied)?;
+    }
+
+    let user_key = DataKey::User(user.clone());
+    if let Some(mut user_data) = users::load_user(env, user) {
+        user_data.total_balance = user_data
+            .total_balance
+            .checked_add(applied)
+            .ok_or(SavingsError::Overflow)?;
+        if applied > 0 {
+            user_data.lifetime_interest = user_data
+                .lifetime_interest
+                .checked_add(applied)
+                .ok_or(SavingsError::Overflow)?;
+        }
+        env.storage().persistent().set(&user_key, &user_data);
+    }
+
+    if applied < 0 {
+        events::emit_flexi_custody_fee(env, user.clone(), -applied);
+    }
+
+    Ok(applied)
+}
+
+/// Moves `amount` into the user's Flexi balance without fees or deposit
+/// points, for internal transfers such as loan disbursements.
+pub(crate) fn credit_flexi_balance(
+    env: &Env,
+    user: &Address,
+    amount: i128,
+) -> Result<(), SavingsError> {
+    accrue_flexi(env, user)?;
+    let mut user_data = users::load_user(env, user).ok_or(SavingsError::UserNotFound)?;
+
+    let flexi_key = DataKey::FlexiBalance(user.clone());
+    let balance: i128 = env.storage().persistent().get(&flexi_key).unwrap_or(0);
+    let new_balance = balance.checked_add(amount).ok_or(SavingsError::Overflow)?;
+    env.storage().persistent().set(&flexi_key, &new_balance);
+
+    if balance == 0 {
+        stats::record_plan_opened(env, PlanKind::Flexi, amount)?;
+    } else {
+        stats::record_principal_added(env, PlanKind::Flexi, amount)?;
+    }
+
+    user_data.total_balance = user_data
+        .total_balance
+        .checked_add(amount)
+        .ok_or(SavingsError::Overflow)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::User(user.clone()), &user_data);
+    ttl::extend_user_ttl(env, user);
+    Ok(())
+}
+
+/// Moves `amount` out of the user's Flexi balance without fees, for internal
+/// transfers such as loan repayments.
+pub(crate) fn debit_flexi_balance(
+    env: &Env,
+    user: &Address,
+    amount: i128,
+) -> Result<(), SavingsError> {
+    accrue_flexi(env, user)?;
+    let mut user_data = users::load_user(env, user).ok_or(SavingsError::UserNotFound)?;
+
+    let flexi_key = DataKey::FlexiBalance(user.clone());
+    let balance: i128 = env.storage().persistent().get(&flexi_key).unwrap_or(0);
+    if balance < amount {
+        return Err(SavingsError::InsufficientBalance);
+    }
+    let new_balance = balance - amount;
+    env.storage().persistent().set(&flexi_key, &new_balance);
+
+    if new_balance == 0 {
+        stats::record_plan_closed(env, PlanKind::Flexi, amount)?;
+    } else {
+        stats::record_principal_removed(env, PlanKind::Flexi, amount)?;
+    }
+
+    user_data.total_balance = user_data
+        .total_balance
+        .checked_sub(amount)
+        .ok_or(SavingsError::Underflow)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::User(user.clone()), &user_data);
+    ttl::extend_user_ttl(env, user);
+    Ok(())
+}
+
 /// Returns the user's Flexi Save balance.
 /// This is a read-only (view) function.
 pub fn get_flexi_balance(env: &Env, user: Address) -> Result<i128, SavingsError> {
     // 1. Ensure user exists
-    let user_key = DataKey::User(user.clone());
-    let _user: User = env
-        .storage()
-        .persistent()
-        .get(&user_key)
-        .ok_or(SavingsError::UserNotFound)?;
+    if !users::user_exists(env, &user) {
+        return Err(SavingsError::UserNotFound);
+    }
 
     // 2. Read flexi balance (default to 0)
     let flexi_key = DataKey::FlexiBalance(user.clone());
@@ -203,8 +445,13 @@ pub fn has_flexi_balance(env: &Env, user: Address) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use crate::{NesteraContract, NesteraContractClient};
-    use soroban_sdk::{testutils::Address as _, Address, Env};
+    use crate::{NesteraContract, NesteraContractClient, SavingsError};
+    use soroban_sdk::{
+        symbol_short,
+        testutils::{Address as _, Events},
+        token::{StellarAssetClient, TokenClient},
+        Address, Env, IntoVal,
+    };
 
     fn setup_admin_env() -> (Env, NesteraContractClient<'static>, Address) {
         let env = Env::default();
@@ -328,4 +575,116 @@ mod tests {
         assert_eq!(client.get_flexi_balance(&user), 50);
         assert_eq!(client.get_protocol_fee_balance(&treasury), 0);
     }
+
+    #[test]
+    fn test_flexi_deposit_for_credits_beneficiary() {
+        let (env, client, _admin) = setup_admin_env();
+        let payer = Address::generate(&env);
+        let beneficiary = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&payer);
+        client.initialize_user(&beneficiary);
+        client.deposit_flexi(&payer, &1_000);
+
+        client.deposit_flexi_for(&payer, &beneficiary, &2_500);
+
+        // Only the payer signed the gift
+        let auths = env.auths();
+        assert_eq!(auths.len(), 1);
+        assert_eq!(auths[0].0, payer);
+
+        let events = env.events().all();
+        let (_, topics, data) = events.last().unwrap();
+        assert_eq!(
+            topics,
+            (symbol_short!("dep_for"), payer.clone(), beneficiary.clone()).into_val(&env)
+        );
+        let funded: i128 = data.into_val(&env);
+        assert_eq!(funded, 2_500);
+
+        assert_eq!(client.get_flexi_balance(&beneficiary), 2_500);
+        assert_eq!(client.get_user(&beneficiary).total_balance, 2_500);
+        assert_eq!(client.get_flexi_balance(&payer), 1_000);
+    }
+
+    #[test]
+    fn test_flexi_deposit_for_transfers_from_payer_when_token_set() {
+        let (env, client, admin) = setup_admin_env();
+        let payer = Address::generate(&env);
+        let beneficiary = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&beneficiary);
+
+        let asset = env.register_stellar_asset_contract_v2(admin.clone());
+        let token = asset.address();
+        StellarAssetClient::new(&env, &token).mint(&payer, &10_000);
+        env.as_contract(&client.address, || {
+            crate::config::set_token(&env, &token).unwrap();
+        });
+
+        client.deposit_flexi_for(&payer, &beneficiary, &2_500);
+
+        let token_client = TokenClient::new(&env, &token);
+        assert_eq!(token_client.balance(&payer), 7_500);
+        assert_eq!(token_client.balance(&client.address), 2_500);
+        assert_eq!(client.get_flexi_balance(&beneficiary), 2_500);
+    }
+
+    #[test]
+    fn test_flexi_deposit_for_uninitialized_beneficiary_rejected() {
+        let (env, client, _admin) = setup_admin_env();
+        let payer = Address::generate(&env);
+        let beneficiary = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&payer);
+
+        let result = client.try_deposit_flexi_for(&payer, &beneficiary, &2_500);
+        assert_eq!(result, Err(Ok(SavingsError::UserNotFound)));
+        assert_eq!(client.get_flexi_balance(&beneficiary), 0);
+    }
+
+    #[test]
+    fn test_flexi_deposit_near_i128_max_returns_overflow() {
+        let (env, client, _admin) = setup_admin_env();
+        let user = Address::generate(&env);
+
+        client.initialize_user(&user);
+        client.deposit_flexi(&user, &(i128::MAX - 10));
+
+        let result = client.try_deposit_flexi(&user, &100);
+        assert_eq!(result, Err(Ok(SavingsError::Overflow)));
+        assert_eq!(client.get_flexi_balance(&user), i128::MAX - 10);
+    }
+
+    #[test]
+    fn test_min_flexi_deposit_boundary() {
+        let (env, client, admin) = setup_admin_env();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        assert_eq!(client.get_min_flexi_deposit(), None);
+        client.set_min_flexi_deposit(&admin, &Some(100));
+        assert_eq!(client.get_min_flexi_deposit(), Some(100));
+
+        assert_eq!(
+            client.try_deposit_flexi(&user, &99),
+            Err(Ok(SavingsError::InvalidAmount))
+        );
+        client.deposit_flexi(&user, &100);
+        assert_eq!(client.get_flexi_balance(&user), 100);
+
+        // Clearing the minimum accepts dust again
+        client.set_min_flexi_deposit(&admin, &None);
+        client.deposit_flexi(&user, &1);
+        assert_eq!(client.get_flexi_balance(&user), 101);
+
+        let stranger = Address::generate(&env);
+        assert_eq!(
+            client.try_set_min_flexi_deposit(&stranger, &Some(100)),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+    }
 }
@@ -1,12 +1,35 @@
-use soroban_sdk::{symbol_short, Address, Env, Vec};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Vec};
 
 use crate::calculate_fee;
-use crate::ensure_not_paused;
 use crate::errors::SavingsError;
+use crate::events;
+use crate::penalty;
 use crate::rewards::storage;
-use crate::storage_types::{DataKey, GoalSave, User};
+use crate::stats::{self, PlanKind};
+use crate::storage_types::{DataKey, GoalSave};
 use crate::ttl;
 use crate::users;
+use crate::{ensure_not_paused, ensure_withdrawals_allowed};
+
+/// Storage keys for contributions made to a goal by someone other than its owner
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GoalShareKey {
+    /// Net amount an address has contributed to a goal
+    Contribution(u64, Address),
+    /// Addresses that have contributed to a goal, in first-contribution order
+    Contributors(u64),
+}
+
+/// Goal storage kept outside `GoalSave`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GoalConfigKey {
+    /// `(interval, last_reminded)` for goals with reminders turned on
+    Reminder(u64),
+    /// Goal payouts credited to an address's `total_balance`
+    PaidOut(Address),
+}
 
 pub fn create_goal_save(
     env: &Env,
@@ -16,6 +39,7 @@ pub fn create_goal_save(
     initial_deposit: i128,
 ) -> Result<u64, SavingsError> {
     ensure_not_paused(env)?;
+    crate::config::ensure_plan_type_enabled(env, PlanKind::Goal)?;
     user.require_auth();
 
     if target_amount <= 0 {
@@ -60,6 +84,17 @@ pub fn create_goal_save(
     env.storage()
         .persistent()
         .set(&DataKey::GoalSave(goal_id), &goal_save);
+    stats::record_plan_opened(env, PlanKind::Goal, net_initial_deposit)?;
+
+    let user_key = DataKey::User(user.clone());
+    if let Some(mut user_data) = users::load_user(env, &user) {
+        user_data.savings_count = user_data
+            .savings_count
+            .checked_add(1)
+            .ok_or(SavingsError::Overflow)?;
+        user_data.record_activity(current_time, initial_deposit > 0);
+        env.storage().persistent().set(&user_key, &user_data);
+    }
 
     if goal_save.is_completed {
         storage::award_goal_completion_bonus(env, user.clone())?;
@@ -82,10 +117,7 @@ pub fn create_goal_save(
                 .checked_add(fee_amount)
                 .ok_or(SavingsError::Overflow)?;
             env.storage().persistent().set(&fee_key, &new_fee_balance);
-            env.events().publish(
-                (symbol_short!("gdep_fee"), fee_recipient, goal_id),
-                fee_amount,
-            );
+            events::emit_goal_deposit_fee(env, fee_recipient, goal_id, fee_amount);
         }
     }
 
@@ -94,6 +126,9 @@ pub fn create_goal_save(
 
     // Award deposit points
     storage::award_deposit_points(env, user.clone(), initial_deposit)?;
+    if initial_deposit > 0 {
+        stats::issue_deposit_receipt(env, &user, initial_deposit)?;
+    }
 
     // Extend TTL for new goal save and user data
     ttl::extend_goal_ttl(env, goal_id);
@@ -115,7 +150,7 @@ pub fn deposit_to_goal_save(
         return Err(SavingsError::InvalidAmount);
     }
 
-    let mut goal_save = get_goal_save(env, goal_id).ok_or(SavingsError::PlanNotFound)?;
+    let goal_save = get_goal_save(env, goal_id).ok_or(SavingsError::PlanNotFound)?;
 
     if goal_save.owner != user {
         return Err(SavingsError::Unauthorized);
@@ -125,76 +160,76 @@ pub fn deposit_to_goal_save(
         return Err(SavingsError::PlanCompleted);
     }
 
-    // Calculate protocol fee
-    let fee_bps: u32 = env
-        .storage()
-        .instance()
-        .get(&DataKey::PlatformFee)
-        .unwrap_or(0);
+    credit_goal_deposit(env, &user, goal_save, amount)?;
+    Ok(())
+}
 
-    let fee_amount = calculate_fee(amount, fee_bps)?;
-    let net_amount = amount
-        .checked_sub(fee_amount)
-        .ok_or(SavingsError::Underflow)?;
+/// Contributes to any open goal, not just one the contributor owns.
+///
+/// Unlike group saves there is no membership step: any registered user can
+/// chip in. Contributions from non-owners are tracked per address so the
+/// goal can be paid out proportionally when it is withdrawn or broken.
+pub fn contribute_shared_goal(
+    env: &Env,
+    contributor: Address,
+    goal_id: u64,
+    amount: i128,
+) -> Result<(), SavingsError> {
+    ensure_not_paused(env)?;
+    contributor.require_auth();
 
-    goal_save.current_amount = goal_save
-        .current_amount
-        .checked_add(net_amount)
-        .ok_or(SavingsError::Overflow)?;
+    if amount <= 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
 
-    let was_completed = goal_save.is_completed;
-    if goal_save.current_amount >= goal_save.target_amount {
-        goal_save.is_completed = true;
+    if !users::user_exists(env, &contributor) {
+        return Err(SavingsError::UserNotFound);
     }
 
-    env.storage()
-        .persistent()
-        .set(&DataKey::GoalSave(goal_id), &goal_save);
+    let goal_save = get_goal_save(env, goal_id).ok_or(SavingsError::PlanNotFound)?;
 
-    if !was_completed && goal_save.is_completed {
-        storage::award_goal_completion_bonus(env, user.clone())?;
+    if goal_save.is_completed || goal_save.is_withdrawn {
+        return Err(SavingsError::PlanCompleted);
     }
 
-    // Extend TTL on deposit
-    ttl::extend_goal_ttl(env, goal_id);
-    ttl::extend_user_ttl(env, &user);
+    let is_owner = goal_save.owner == contributor;
+    let net_amount = credit_goal_deposit(env, &contributor, goal_save, amount)?;
 
-    // Transfer fee to treasury if fee > 0
-    if fee_amount > 0 {
-        if let Some(fee_recipient) = env
-            .storage()
-            .instance()
-            .get::<DataKey, Address>(&DataKey::FeeRecipient)
-        {
-            let fee_key = DataKey::TotalBalance(fee_recipient.clone());
-            let current_fee_balance = env
-                .storage()
-                .persistent()
-                .get::<DataKey, i128>(&fee_key)
-                .unwrap_or(0i128);
-            let new_fee_balance = current_fee_balance
-                .checked_add(fee_amount)
-                .ok_or(SavingsError::Overflow)?;
-            env.storage().persistent().set(&fee_key, &new_fee_balance);
-            env.events().publish(
-                (symbol_short!("gdep_fee"), fee_recipient, goal_id),
-                fee_amount,
-            );
-        }
+    if !is_owner {
+        record_contribution(env, goal_id, &contributor, net_amount)?;
     }
 
-    // Award deposit points
-    storage::award_deposit_points(env, user.clone(), amount)?;
-
     Ok(())
 }
 
+/// Returns the net principal `user` has put into the goal.
+///
+/// The owner's share is whatever part of the balance was not contributed by
+/// someone else.
+pub fn get_contributor_share(
+    env: &Env,
+    goal_id: u64,
+    user: &Address,
+) -> Result<i128, SavingsError> {
+    let goal_save = get_goal_save(env, goal_id).ok_or(SavingsError::PlanNotFound)?;
+
+    if goal_save.owner == *user {
+        let contributed = total_contributions(env, goal_id)?;
+        return goal_save
+            .current_amount
+            .checked_sub(contributed)
+            .ok_or(SavingsError::Underflow);
+    }
+
+    Ok(get_contribution(env, goal_id, user))
+}
+
 pub fn withdraw_completed_goal_save(
     env: &Env,
     user: Address,
     goal_id: u64,
 ) -> Result<i128, SavingsError> {
-    ensure_not_paused(env)?;
+    ensure_withdrawals_allowed(env)?;
     user.require_auth();
 
     if !users::user_exists(env, &user) {
@@ -233,15 +268,9 @@ pub fn withdraw_completed_goal_save(
     env.storage()
         .persistent()
         .set(&DataKey::GoalSave(goal_id), &goal_save);
+    stats::record_plan_closed(env, PlanKind::Goal, goal_save.current_amount)?;
 
-    let user_key = DataKey::User(user.clone());
-    if let Some(mut user_data) = env.storage().persistent().get::<DataKey, User>(&user_key) {
-        user_data.total_balance = user_data
-            .total_balance
-            .checked_add(net_amount)
-            .ok_or(SavingsError::Overflow)?;
-        env.storage().persistent().set(&user_key, &user_data);
-    }
+    let owner_amount = pay_out_goal(env, &goal_save, net_amount)?;
 
     // Extend TTL (withdrawn goals get shorter extension)
     ttl::extend_goal_ttl(env, goal_id);
@@ -264,18 +293,15 @@ pub fn withdraw_completed_goal_save(
                 .checked_add(fee_amount)
                 .ok_or(SavingsError::Overflow)?;
             env.storage().persistent().set(&fee_key, &new_fee_balance);
-            env.events().publish(
-                (symbol_short!("gwth_fee"), fee_recipient, goal_id),
-                fee_amount,
-            );
+            events::emit_goal_withdraw_fee(env, fee_recipient, goal_id, fee_amount);
         }
     }
 
-    Ok(net_amount)
+    Ok(owner_amount)
 }
 
 pub fn break_goal_save(env: &Env, user: Address, goal_id: u64) -> Result<i128, SavingsError> {
-    ensure_not_paused(env)?;
+    ensure_withdrawals_allowed(env)?;
     user.require_auth();
 
     if !users::user_exists(env, &user) {
@@ -315,6 +341,7 @@ pub fn break_goal_save(env: &Env, user: Address, goal_id: u64) -> Result<i128, S
             .ok_or(SavingsError::Overflow)?
             / 10_000
     };
+    let fee_amount = crate::rewards::redemption::apply_penalty_discount(env, &user, fee_amount)?;
 
     let net_amount = goal_save
         .current_amount
@@ -326,17 +353,14 @@ pub fn break_goal_save(env: &Env, user: Address, goal_id: u64) -> Result<i128, S
     env.storage()
         .persistent()
         .set(&DataKey::GoalSave(goal_id), &goal_save);
+    stats::record_plan_closed(env, PlanKind::Goal, goal_save.current_amount)?;
 
-    let user_key = DataKey::User(user.clone());
-    if let Some(mut user_data) = env.storage().persistent().get::<DataKey, User>(&user_key) {
-        user_data.total_balance = user_data
-            .total_balance
-            .checked_add(net_amount)
-            .ok_or(SavingsError::Overflow)?;
-        env.storage().persistent().set(&user_key, &user_data);
-    }
+    let owner_amount = pay_out_goal(env, &goal_save, net_amount)?;
 
-    if fee_amount > 0 {
+    // Part of the penalty may go to the interest reserve and stakers
+    let fee_pool_amount = penalty::distribute_penalty(env, fee_amount)?;
+
+    if fee_pool_amount > 0 {
         if let Some(fee_recipient) = env
             .storage()
             .instance()
@@ -349,24 +373,18 @@ pub fn break_goal_save(env: &Env, user: Address, goal_id: u64) -> Result<i128, S
                 .get::<DataKey, i128>(&fee_key)
                 .unwrap_or(0i128);
             let new_fee_balance = current_fee_balance
-                .checked_add(fee_amount)
+                .checked_add(fee_pool_amount)
                 .ok_or(SavingsError::Overflow)?;
             env.storage().persistent().set(&fee_key, &new_fee_balance);
 
             // Extend TTL on fee storage
             ttl::extend_config_ttl(env, &fee_key);
 
-            env.events().publish(
-                (symbol_short!("brk_fee"), fee_recipient, goal_id),
-                fee_amount,
-            );
+            events::emit_goal_break_fee(env, fee_recipient, goal_id, fee_pool_amount);
         }
     }
 
-    env.events().publish(
-        (symbol_short!("goal_brk"), user.clone(), goal_id),
-        net_amount,
-    );
+    events::emit_goal_broken(env, user.clone(), goal_id, net_amount);
 
     remove_goal_from_user(env, &user, goal_id);
 
@@ -374,9 +392,293 @@ pub fn break_goal_save(env: &Env, user: Address, goal_id: u64) -> Result<i128, S
     ttl::extend_goal_ttl(env, goal_id);
     ttl::extend_user_ttl(env, &user);
 
+    Ok(owner_amount)
+}
+
+/// Applies the platform fee to `amount` and adds the rest to the goal.
+///
+/// Returns the net amount credited.
+fn credit_goal_deposit(
+    env: &Env,
+    depositor: &Address,
+    mut goal_save: GoalSave,
+    amount: i128,
+) -> Result<i128, SavingsError> {
+    let goal_id = goal_save.id;
+
+    // Calculate protocol fee
+    let fee_bps: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::PlatformFee)
+        .unwrap_or(0);
+
+    let fee_amount = calculate_fee(amount, fee_bps)?;
+    let net_amount = amount
+        .checked_sub(fee_amount)
+        .ok_or(SavingsError::Underflow)?;
+
+    goal_save.current_amount = goal_save
+        .current_amount
+        .checked_add(net_amount)
+        .ok_or(SavingsError::Overflow)?;
+
+    let was_completed = goal_save.is_completed;
+    if goal_save.current_amount >= goal_save.target_amount {
+        goal_save.is_completed = true;
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::GoalSave(goal_id), &goal_save);
+    stats::record_principal_added(env, PlanKind::Goal, net_amount)?;
+    users::record_activity(env, depositor, true);
+
+    if !was_completed && goal_save.is_completed {
+        storage::award_goal_completion_bonus(env, goal_save.owner.clone())?;
+    }
+
+    // Extend TTL on deposit
+    ttl::extend_goal_ttl(env, goal_id);
+    ttl::extend_user_ttl(env, depositor);
+
+    // Transfer fee to treasury if fee > 0
+    if fee_amount > 0 {
+        if let Some(fee_recipient) = env
+            .storage()
+            .instance()
+            .get::<DataKey, Address>(&DataKey::FeeRecipient)
+        {
+            let fee_key = DataKey::TotalBalance(fee_recipient.clone());
+            let current_fee_balance = env
+                .storage()
+                .persistent()
+                .get::<DataKey, i128>(&fee_key)
+                .unwrap_or(0i128);
+            let new_fee_balance = current_fee_balance
+                .checked_add(fee_amount)
+                .ok_or(SavingsError::Overflow)?;
+            env.storage().persistent().set(&fee_key, &new_fee_balance);
+            events::emit_goal_deposit_fee(env, fee_recipient, goal_id, fee_amount);
+        }
+    }
+
+    // Award deposit points
+    storage::award_deposit_points(env, depositor.clone(), amount)?;
+    stats::issue_deposit_receipt(env, depositor, amount)?;
+
     Ok(net_amount)
 }
 
+/// Sets how often `emit_goal_reminders` may remind the owner about the goal.
+/// An interval of 0 turns reminders off.
+pub fn set_goal_reminder_interval(
+    env: &Env,
+    user: Address,
+    goal_id: u64,
+    interval: u64,
+) -> Result<(), SavingsError> {
+    user.require_auth();
+
+    let goal_save = ensure_open_goal_of(env, &user, goal_id)?;
+    let key = GoalConfigKey::Reminder(goal_id);
+    if interval == 0 {
+        env.storage().persistent().remove(&key);
+        return Ok(());
+    }
+
+    // Until the first reminder the cadence counts from the goal's start
+    let last_reminded = get_goal_reminder(env, goal_id)
+        .map(|(_, last)| last)
+        .unwrap_or(goal_save.start_time);
+    env.storage()
+        .persistent()
+        .set(&key, &(interval, last_reminded));
+    Ok(())
+}
+
+/// The goal's `(interval, last_reminded)`, if reminders are turned on
+pub fn get_goal_reminder(env: &Env, goal_id: u64) -> Option<(u64, u64)> {
+    env.storage()
+        .persistent()
+        .get(&GoalConfigKey::Reminder(goal_id))
+}
+
+/// Publishes a `remind` event for each open goal whose reminder interval has
+/// elapsed since its last reminder, and returns the IDs reminded.
+///
+/// Callable by anyone, typically an off-chain notification keeper. IDs that
+/// are unknown, closed, not due or repeated are skipped. At most
+/// `events::MAX_BATCH_ITEMS` IDs are accepted per call.
+pub fn emit_goal_reminders(env: &Env, goal_ids: Vec<u64>) -> Result<Vec<u64>, SavingsError> {
+    if goal_ids.len() > events::MAX_BATCH_ITEMS {
+        return Err(SavingsError::ResultTooLarge);
+    }
+
+    let now = env.ledger().timestamp();
+    let mut reminded = Vec::new(env);
+    for goal_id in goal_ids.iter() {
+        if reminded.contains(goal_id) {
+            continue;
+        }
+        let Some(goal_save) = get_goal_save(env, goal_id) else {
+            continue;
+        };
+        let Some((interval, last_reminded)) = get_goal_reminder(env, goal_id) else {
+            continue;
+        };
+        if goal_save.is_completed || goal_save.is_withdrawn {
+            continue;
+        }
+        if now < last_reminded.saturating_add(interval) {
+            continue;
+        }
+
+        env.storage()
+            .persistent()
+            .set(&GoalConfigKey::Reminder(goal_id), &(interval, now));
+        events::emit_goal_reminder(
+            env,
+            goal_save.owner,
+            goal_id,
+            goal_save.current_amount,
+            goal_save.target_amount,
+        );
+        reminded.push_back(goal_id);
+    }
+
+    events::emit_batch_summary(env, symbol_short!("remind"), goal_ids.len(), reminded.len());
+    Ok(reminded)
+}
+
+/// Checks that `goal_id` is an open goal owned by `owner`
+pub(crate) fn ensure_open_goal_of(
+    env: &Env,
+    owner: &Address,
+    goal_id: u64,
+) -> Result<GoalSave, SavingsError> {
+    let goal_save = get_goal_save(env, goal_id).ok_or(SavingsError::PlanNotFound)?;
+
+    if goal_save.owner != *owner {
+        return Err(SavingsError::Unauthorized);
+    }
+    if goal_save.is_completed || goal_save.is_withdrawn {
+        return Err(SavingsError::PlanCompleted);
+    }
+    Ok(goal_save)
+}
+
+/// Moves a matured lock's proceeds into the owner's goal
+pub(crate) fn deposit_matured_lock(
+    env: &Env,
+    owner: &Address,
+    goal_id: u64,
+    amount: i128,
+) -> Result<i128, SavingsError> {
+    let goal_save = ensure_open_goal_of(env, owner, goal_id)?;
+    credit_goal_deposit(env, owner, goal_save, amount)
+}
+
+fn get_contribution(env: &Env, goal_id: u64, user: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&GoalShareKey::Contribution(goal_id, user.clone()))
+        .unwrap_or(0)
+}
+
+fn get_contributors(env: &Env, goal_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&GoalShareKey::Contributors(goal_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn total_contributions(env: &Env, goal_id: u64) -> Result<i128, SavingsError> {
+    let mut total = 0i128;
+    for contributor in get_contributors(env, goal_id).iter() {
+        total = total
+            .checked_add(get_contribution(env, goal_id, &contributor))
+            .ok_or(SavingsError::Overflow)?;
+    }
+    Ok(total)
+}
+
+fn record_contribution(
+    env: &Env,
+    goal_id: u64,
+    contributor: &Address,
+    amount: i128,
+) -> Result<(), SavingsError> {
+    let previous = get_contribution(env, goal_id, contributor);
+    if previous == 0 {
+        let mut contributors = get_contributors(env, goal_id);
+        contributors.push_back(contributor.clone());
+        env.storage()
+            .persistent()
+            .set(&GoalShareKey::Contributors(goal_id), &contributors);
+    }
+
+    let updated = previous.checked_add(amount).ok_or(SavingsError::Overflow)?;
+    env.storage().persistent().set(
+        &GoalShareKey::Contribution(goal_id, contributor.clone()),
+        &updated,
+    );
+    Ok(())
+}
+
+/// Splits `net_amount` across the goal's contributors in proportion to what
+/// each put in, crediting their balances. The owner receives the remainder,
+/// including any rounding dust, and that amount is returned.
+fn pay_out_goal(env: &Env, goal_save: &GoalSave, net_amount: i128) -> Result<i128, SavingsError> {
+    let mut owner_amount = net_amount;
+
+    if goal_save.current_amount > 0 {
+        for contributor in get_contributors(env, goal_save.id).iter() {
+            let contribution = get_contribution(env, goal_save.id, &contributor);
+            let payout = net_amount
+                .checked_mul(contribution)
+                .ok_or(SavingsError::Overflow)?
+                / goal_save.current_amount;
+
+            credit_user_balance(env, &contributor, payout)?;
+            owner_amount = owner_amount
+                .checked_sub(payout)
+                .ok_or(SavingsError::Underflow)?;
+            events::emit_goal_share_paid(env, contributor, goal_save.id, payout);
+        }
+    }
+
+    credit_user_balance(env, &goal_save.owner, owner_amount)?;
+    Ok(owner_amount)
+}
+
+fn credit_user_balance(env: &Env, user: &Address, amount: i128) -> Result<(), SavingsError> {
+    let user_key = DataKey::User(user.clone());
+    if let Some(mut user_data) = users::load_user(env, user) {
+        user_data.total_balance = user_data
+            .total_balance
+            .checked_add(amount)
+            .ok_or(SavingsError::Overflow)?;
+        user_data.record_activity(env.ledger().timestamp(), false);
+        env.storage().persistent().set(&user_key, &user_data);
+
+        let paid_key = GoalConfigKey::PaidOut(user.clone());
+        let paid_out = get_goal_payouts(env, user)
+            .checked_add(amount)
+            .ok_or(SavingsError::Overflow)?;
+        env.storage().persistent().set(&paid_key, &paid_out);
+    }
+    Ok(())
+}
+
+/// Total goal payouts credited to `user`'s `total_balance`
+pub(crate) fn get_goal_payouts(env: &Env, user: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&GoalConfigKey::PaidOut(user.clone()))
+        .unwrap_or(0)
+}
+
 pub fn get_goal_save(env: &Env, goal_id: u64) -> Option<GoalSave> {
     let goal_save = env.storage().persistent().get(&DataKey::GoalSave(goal_id));
     if goal_save.is_some() {
@@ -450,10 +752,12 @@ fn remove_goal_from_user(env: &Env, user: &Address, goal_id: u64) {
 
 #[cfg(test)]
 mod tests {
+    use crate::errors::SavingsError;
     use crate::rewards::storage_types::RewardsConfig;
     use crate::{NesteraContract, NesteraContractClient};
     use soroban_sdk::{
-        testutils::{Address as _, Events},
+        symbol_short,
+        testutils::{Address as _, Events, Ledger},
         Address, BytesN, Env, IntoVal, Symbol,
     };
 
@@ -1069,4 +1373,140 @@ mod tests {
         // Base points: 2000 * 10 = 20000
         assert_eq!(rewards.total_points, 20000);
     }
+
+    #[test]
+    fn test_shared_goal_splits_withdrawal_between_contributors() {
+        let (env, client) = setup_test_env();
+        let owner = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&owner);
+        client.initialize_user(&alice);
+        client.initialize_user(&bob);
+
+        let goal_name = Symbol::new(&env, "family");
+        let goal_id = client.create_goal_save(&owner, &goal_name, &1_000, &200);
+
+        client.contribute_shared_goal(&alice, &goal_id, &300);
+        client.contribute_shared_goal(&bob, &goal_id, &500);
+
+        assert!(client.get_goal_save_detail(&goal_id).is_completed);
+        assert_eq!(client.get_contributor_share(&goal_id, &owner), 200);
+        assert_eq!(client.get_contributor_share(&goal_id, &alice), 300);
+        assert_eq!(client.get_contributor_share(&goal_id, &bob), 500);
+
+        let owner_amount = client.withdraw_completed_goal_save(&owner, &goal_id);
+        assert_eq!(owner_amount, 200);
+        assert_eq!(client.get_user(&alice).total_balance, 300);
+        assert_eq!(client.get_user(&bob).total_balance, 500);
+    }
+
+    #[test]
+    fn test_shared_goal_break_refunds_contributors_proportionally() {
+        let (env, client, _admin) = setup_admin_env();
+        let owner = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let treasury = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&owner);
+        client.initialize_user(&alice);
+        client.initialize_user(&bob);
+        assert!(client.try_set_fee_recipient(&treasury).is_ok());
+        assert!(client.try_set_early_break_fee_bps(&1_000).is_ok()); // 10%
+
+        let goal_name = Symbol::new(&env, "shared");
+        let goal_id = client.create_goal_save(&owner, &goal_name, &10_000, &1_000);
+        client.contribute_shared_goal(&alice, &goal_id, &1_000);
+        client.contribute_shared_goal(&bob, &goal_id, &2_000);
+
+        // 4_000 balance less the 10% break fee leaves 3_600 to split 1:1:2
+        let owner_amount = client.break_goal_save(&owner, &goal_id);
+        assert_eq!(owner_amount, 900);
+        assert_eq!(client.get_user(&alice).total_balance, 900);
+        assert_eq!(client.get_user(&bob).total_balance, 1_800);
+        assert_eq!(client.get_protocol_fee_balance(&treasury), 400);
+    }
+
+    #[test]
+    fn test_contribute_shared_goal_rejects_completed_goal() {
+        let (env, client) = setup_test_env();
+        let owner = Address::generate(&env);
+        let alice = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&owner);
+        client.initialize_user(&alice);
+
+        let goal_name = Symbol::new(&env, "done");
+        let goal_id = client.create_goal_save(&owner, &goal_name, &1_000, &1_000);
+
+        assert_eq!(
+            client.try_contribute_shared_goal(&alice, &goal_id, &100),
+            Err(Ok(SavingsError::PlanCompleted))
+        );
+        assert_eq!(client.get_contributor_share(&goal_id, &alice), 0);
+    }
+
+    #[test]
+    fn test_goal_reminders_follow_configured_cadence() {
+        let (env, client, _) = setup_admin_env();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        let day = 86_400u64;
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "car"), &10_000, &1_000);
+        let silent = client.create_goal_save(&user, &Symbol::new(&env, "bike"), &10_000, &1_000);
+        let start = env.ledger().timestamp();
+        client.set_goal_reminder_interval(&user, &goal_id, &(7 * day));
+        assert_eq!(client.get_goal_reminder(&goal_id), Some((7 * day, start)));
+        assert_eq!(client.get_goal_reminder(&silent), None);
+        let ids = soroban_sdk::vec![&env, goal_id, silent];
+
+        // Not due before a full interval has passed
+        env.ledger().with_mut(|li| li.timestamp += 7 * day - 1);
+        assert!(client.emit_goal_reminders(&ids).is_empty());
+
+        env.ledger().with_mut(|li| li.timestamp += 1);
+        assert_eq!(
+            client.emit_goal_reminders(&ids),
+            soroban_sdk::vec![&env, goal_id]
+        );
+        let expected_topics = (symbol_short!("remind"), user.clone(), goal_id).into_val(&env);
+        assert!(env.events().all().iter().any(|(_, topics, data)| {
+            topics == expected_topics && data.shallow_eq(&(1_000i128, 10_000i128).into_val(&env))
+        }));
+
+        // Calling again right away, or a day later, does not repeat it
+        assert!(client.emit_goal_reminders(&ids).is_empty());
+        env.ledger().with_mut(|li| li.timestamp += day);
+        assert!(client.emit_goal_reminders(&ids).is_empty());
+
+        env.ledger().with_mut(|li| li.timestamp += 6 * day);
+        assert_eq!(
+            client.emit_goal_reminders(&ids),
+            soroban_sdk::vec![&env, goal_id]
+        );
+    }
+
+    #[test]
+    fn test_savings_count_is_lifetime_across_plan_types() {
+        let (env, client, _) = setup_admin_env();
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        client.create_savings_plan(&user, &crate::PlanType::Flexi, &1_000);
+        let lock_id = client.create_lock_save(&user, &1_000, &86_400);
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "car"), &10_000, &1_000);
+        assert_eq!(client.get_user(&user).savings_count, 3);
+
+        // Closing plans does not lower the count
+        env.ledger().with_mut(|li| li.timestamp += 86_400);
+        client.withdraw_lock_save(&user, &lock_id);
+        client.break_goal_save(&user, &goal_id);
+        assert_eq!(client.get_user(&user).savings_count, 3);
+    }
 }
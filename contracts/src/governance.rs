@@ -35,10 +35,13 @@ pub fn get_proposal_votes(env: &Env, proposal_id: u64) -> (u128, u128, u128) {
     }
 }
 use crate::errors::SavingsError;
-use crate::governance_events::*;
+use crate::events::{
+    emit_proposal_canceled, emit_proposal_created, emit_proposal_executed, emit_proposal_queued,
+    emit_vote_cast,
+};
 use crate::rewards::storage::get_user_rewards;
 use crate::storage_types::DataKey;
-use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use soroban_sdk::{contracttype, Address, Env, String, Symbol, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -72,7 +75,7 @@ pub struct Proposal {
 }
 
 #[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct VotingConfig {
     pub quorum: u32,
     pub voting_period: u64,
@@ -81,6 +84,50 @@ pub struct VotingConfig {
     pub max_voting_power: u128,
 }
 
+/// Final tallies of a closed proposal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalResult {
+    /// Total voting power cast, abstentions included
+    pub turnout: u128,
+    pub for_votes: u128,
+    pub against_votes: u128,
+    pub abstain_votes: u128,
+    /// Whether turnout reached the configured `quorum`
+    pub quorum_met: bool,
+    /// Quorum met and more votes for than against
+    pub passed: bool,
+    /// Address that executed the proposal, if it has been executed
+    pub executor: Option<Address>,
+    /// Ledger timestamp of execution (0 until executed)
+    pub executed_at: u64,
+}
+
+/// Snapshot of governance state for front-ends
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceStatus {
+    pub is_active: bool,
+    /// Whether `init_voting_config` has run; `config` is all zeros otherwise
+    pub is_configured: bool,
+    pub config: VotingConfig,
+    pub next_proposal_id: u64,
+    pub proposal_count: u32,
+}
+
+/// Cumulative governance participation counters
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GovernanceStats {
+    /// Proposals created, plain and action
+    pub total_proposals: u64,
+    pub executed_proposals: u64,
+    /// Distinct addresses that have cast at least one vote
+    pub unique_voters: u64,
+    /// Sum of the (capped) weight of every vote cast
+    pub total_voting_power_cast: u128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum GovernanceKey {
@@ -91,8 +138,40 @@ pub enum GovernanceKey {
     AllProposals,
     GovernanceActive,
     VoterRecord(u64, Address),
+    /// Minimum seconds between two proposals from the same creator
+    ProposalCooldown,
+    /// Last proposal creation time per creator
+    LastProposalTime(Address),
+    /// Maximum proposal description length in bytes
+    MaxDescriptionLen,
+    /// Participation counters returned by `get_governance_stats`
+    Stats,
+    /// Set once an address casts its first vote on any proposal
+    HasEverVoted(Address),
+    /// Metric `get_voting_power` weighs votes by
+    PowerSource,
+    /// Smallest voting power accepted by `vote`
+    MinVoteWeight,
+    /// `(executor, executed_at)` recorded by `execute_proposal`
+    Execution(u64),
+}
+
+/// What a voter's weight is measured in
+#[contracttype]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum VotingPowerSource {
+    /// Everything the user has ever deposited, per the rewards ledger
+    #[default]
+    LifetimeDeposited,
+    /// The user's current `total_balance` across all plans
+    CurrentTotalBalance,
+    /// Principal the user currently holds in active locks
+    CurrentLocked,
 }
 
+/// Description length limit used until the admin configures one
+pub const DEFAULT_MAX_DESCRIPTION_LEN: u32 = 256;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ProposalAction {
@@ -100,14 +179,118 @@ pub enum ProposalAction {
     SetGoalRate(i128),
     SetGroupRate(i128),
     SetLockRate(u64, i128),
+    /// Sets the (min, max) principal accepted for new lock saves
+    SetLockLimits(i128, i128),
     PauseContract,
     UnpauseContract,
+    /// Migrates to a new asset contract (rejected while locks are open)
+    SetToken(Address),
+    /// Changes the metric votes are weighted by
+    SetPowerSource(VotingPowerSource),
+    /// Permanently shuts the protocol down; see `emergency_shutdown`
+    EmergencyShutdown,
 }
 
-/// Calculates voting power for a user based on their lifetime deposited funds
+/// Flattened view of a `ProposalAction` for clients that can't easily match
+/// on the enum.
+///
+/// `action_type` is one of `flexi_rt`, `goal_rt`, `group_rt`, `lock_rt`,
+/// `lock_lim`, `pause`, `unpause`, `set_token`, `power_src` or `shutdown`. Unused
+/// parameters are 0 / `None`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionDescription {
+    pub action_type: Symbol,
+    /// Rate for the rate actions, duration for `lock_rt`, minimum for
+    /// `lock_lim`, `VotingPowerSource` index for `power_src`
+    pub param1: i128,
+    /// Rate for `lock_rt`, maximum for `lock_lim`
+    pub param2: i128,
+    /// New asset for `set_token`
+    pub address: Option<Address>,
+}
+
+impl ActionDescription {
+    fn new(env: &Env, action: &ProposalAction) -> Self {
+        let (tag, param1, param2, address) = match action {
+            ProposalAction::SetFlexiRate(rate) => ("flexi_rt", *rate, 0, None),
+            ProposalAction::SetGoalRate(rate) => ("goal_rt", *rate, 0, None),
+            ProposalAction::SetGroupRate(rate) => ("group_rt", *rate, 0, None),
+            ProposalAction::SetLockRate(duration, rate) => {
+                ("lock_rt", *duration as i128, *rate, None)
+            }
+            ProposalAction::SetLockLimits(min, max) => ("lock_lim", *min, *max, None),
+            ProposalAction::PauseContract => ("pause", 0, 0, None),
+            ProposalAction::UnpauseContract => ("unpause", 0, 0, None),
+            ProposalAction::SetToken(token) => ("set_token", 0, 0, Some(token.clone())),
+            ProposalAction::SetPowerSource(source) => ("power_src", *source as i128, 0, None),
+            ProposalAction::EmergencyShutdown => ("shutdown", 0, 0, None),
+        };
+        ActionDescription {
+            action_type: Symbol::new(env, tag),
+            param1,
+            param2,
+            address,
+        }
+    }
+}
+
+/// Describes the action of an action proposal, or `None` for plain
+/// proposals and unknown IDs
+pub fn describe_action(env: &Env, proposal_id: u64) -> Option<ActionDescription> {
+    let proposal = get_action_proposal(env, proposal_id)?;
+    Some(ActionDescription::new(env, &proposal.action))
+}
+
+/// Calculates voting power for a user from the configured `VotingPowerSource`
 pub fn get_voting_power(env: &Env, user: &Address) -> u128 {
-    let rewards = get_user_rewards(env, user.clone());
-    rewards.lifetime_deposited.max(0) as u128
+    let power = match get_voting_power_source(env) {
+        VotingPowerSource::LifetimeDeposited => {
+            get_user_rewards(env, user.clone()).lifetime_deposited
+        }
+        VotingPowerSource::CurrentTotalBalance => crate::users::load_user(env, user)
+            .map(|u| u.total_balance)
+            .unwrap_or(0),
+        VotingPowerSource::CurrentLocked => crate::lock::get_total_locked(env, user).unwrap_or(0),
+    };
+    power.max(0) as u128
+}
+
+/// Metric votes are currently weighted by
+pub fn get_voting_power_source(env: &Env) -> VotingPowerSource {
+    env.storage()
+        .persistent()
+        .get(&GovernanceKey::PowerSource)
+        .unwrap_or_default()
+}
+
+/// Chooses the voting power metric (admin only; afterwards use a
+/// `SetPowerSource` proposal)
+pub fn set_voting_power_source(
+    env: &Env,
+    admin: Address,
+    source: VotingPowerSource,
+) -> Result<(), SavingsError> {
+    admin.require_auth();
+
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(SavingsError::Unauthorized)?;
+
+    if admin != stored_admin {
+        return Err(SavingsError::Unauthorized);
+    }
+    if is_governance_active(env) {
+        return Err(SavingsError::Unauthorized);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&GovernanceKey::PowerSource, &source);
+
+    Ok(())
 }
 
 /// Creates a new governance proposal
@@ -119,15 +302,18 @@ pub fn create_proposal(
     creator.require_auth();
 
     let config = get_voting_config(env)?;
-    let proposal_id = get_next_proposal_id(env);
+    let proposal_id = get_unused_proposal_id(env)?;
     let now = env.ledger().timestamp();
+    let end_time = voting_end_time(now, &config)?;
+    validate_description(env, &description)?;
+    enforce_proposal_cooldown(env, &creator, now)?;
 
     let proposal = Proposal {
         id: proposal_id,
         creator: creator.clone(),
         description: description.clone(),
         start_time: now,
-        end_time: now + config.voting_period,
+        end_time,
         executed: false,
         for_votes: 0,
         against_votes: 0,
@@ -153,6 +339,10 @@ pub fn create_proposal(
         .persistent()
         .set(&GovernanceKey::NextProposalId, &(proposal_id + 1));
 
+    update_stats(env, |stats| {
+        stats.total_proposals = stats.total_proposals.saturating_add(1)
+    });
+
     // Emit event
     emit_proposal_created(env, proposal_id, creator, description);
 
@@ -173,15 +363,18 @@ pub fn create_action_proposal(
         return Err(SavingsError::InsufficientBalance);
     }
 
-    let proposal_id = get_next_proposal_id(env);
+    let proposal_id = get_unused_proposal_id(env)?;
     let now = env.ledger().timestamp();
+    let end_time = voting_end_time(now, &config)?;
+    validate_description(env, &description)?;
+    enforce_proposal_cooldown(env, &creator, now)?;
 
     let proposal = ActionProposal {
         id: proposal_id,
         creator: creator.clone(),
         description: description.clone(),
         start_time: now,
-        end_time: now + config.voting_period,
+        end_time,
         executed: false,
         for_votes: 0,
         against_votes: 0,
@@ -208,6 +401,10 @@ pub fn create_action_proposal(
         .persistent()
         .set(&GovernanceKey::NextProposalId, &(proposal_id + 1));
 
+    update_stats(env, |stats| {
+        stats.total_proposals = stats.total_proposals.saturating_add(1)
+    });
+
     // Emit event
     emit_proposal_created(env, proposal_id, creator, description);
 
@@ -236,6 +433,47 @@ pub fn list_proposals(env: &Env) -> Vec<u64> {
         .unwrap_or(Vec::new(env))
 }
 
+/// Summarizes a proposal whose voting period has ended.
+///
+/// Works for both plain and action proposals; errors with `TooEarly` while
+/// voting is still open.
+pub fn get_proposal_result(env: &Env, proposal_id: u64) -> Result<ProposalResult, SavingsError> {
+    let (end_time, for_votes, against_votes, abstain_votes) =
+        if let Some(p) = get_proposal(env, proposal_id) {
+            (p.end_time, p.for_votes, p.against_votes, p.abstain_votes)
+        } else if let Some(p) = get_action_proposal(env, proposal_id) {
+            (p.end_time, p.for_votes, p.against_votes, p.abstain_votes)
+        } else {
+            return Err(SavingsError::PlanNotFound);
+        };
+
+    if env.ledger().timestamp() <= end_time {
+        return Err(SavingsError::TooEarly);
+    }
+
+    let config = get_voting_config(env)?;
+    let turnout = for_votes
+        .checked_add(against_votes)
+        .and_then(|t| t.checked_add(abstain_votes))
+        .ok_or(SavingsError::Overflow)?;
+    let quorum_met = turnout >= config.quorum as u128;
+    let (executor, executed_at) = match get_proposal_execution(env, proposal_id) {
+        Some((executor, executed_at)) => (Some(executor), executed_at),
+        None => (None, 0),
+    };
+
+    Ok(ProposalResult {
+        turnout,
+        for_votes,
+        against_votes,
+        abstain_votes,
+        quorum_met,
+        passed: quorum_met && for_votes > against_votes,
+        executor,
+        executed_at,
+    })
+}
+
 /// Gets the voting configuration
 pub fn get_voting_config(env: &Env) -> Result<VotingConfig, SavingsError> {
     env.storage()
@@ -244,6 +482,22 @@ pub fn get_voting_config(env: &Env) -> Result<VotingConfig, SavingsError> {
         .ok_or(SavingsError::InternalError)
 }
 
+/// Bundles activation state, voting config and proposal counters.
+///
+/// Never errors: an unconfigured governance reports `is_configured = false`
+/// and a zeroed config.
+pub fn get_governance_status(env: &Env) -> GovernanceStatus {
+    let config: Option<VotingConfig> = env.storage().persistent().get(&GovernanceKey::VotingConfig);
+
+    GovernanceStatus {
+        is_active: is_governance_active(env),
+        is_configured: config.is_some(),
+        config: config.unwrap_or_default(),
+        next_proposal_id: get_next_proposal_id(env),
+        proposal_count: list_proposals(env).len(),
+    }
+}
+
 /// Initializes voting configuration (admin only)
 pub fn init_voting_config(
     env: &Env,
@@ -280,6 +534,140 @@ pub fn init_voting_config(
     Ok(())
 }
 
+/// Computes the end of a voting window opening at `now`.
+///
+/// Defends against a zero-length window, which would make a proposal
+/// unvotable because it would open and close in the same instant.
+fn voting_end_time(now: u64, config: &VotingConfig) -> Result<u64, SavingsError> {
+    let end_time = now
+        .checked_add(config.voting_period)
+        .ok_or(SavingsError::Overflow)?;
+    if end_time <= now {
+        return Err(SavingsError::InvalidTimestamp);
+    }
+    Ok(end_time)
+}
+
+/// Sets the per-creator proposal cooldown window in seconds (admin only)
+pub fn set_proposal_cooldown(env: &Env, admin: Address, seconds: u64) -> Result<(), SavingsError> {
+    admin.require_auth();
+
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(SavingsError::Unauthorized)?;
+
+    if admin != stored_admin {
+        return Err(SavingsError::Unauthorized);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&GovernanceKey::ProposalCooldown, &seconds);
+
+    Ok(())
+}
+
+/// Returns the per-creator proposal cooldown window (0 = disabled)
+pub fn get_proposal_cooldown(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&GovernanceKey::ProposalCooldown)
+        .unwrap_or(0)
+}
+
+/// Rejects a creator still inside their cooldown window, then records `now`
+/// as their latest proposal time.
+fn enforce_proposal_cooldown(env: &Env, creator: &Address, now: u64) -> Result<(), SavingsError> {
+    let key = GovernanceKey::LastProposalTime(creator.clone());
+    let cooldown = get_proposal_cooldown(env);
+
+    if let Some(last) = env.storage().persistent().get::<_, u64>(&key) {
+        if now < last.saturating_add(cooldown) {
+            return Err(SavingsError::ProposalCooldownActive);
+        }
+    }
+
+    env.storage().persistent().set(&key, &now);
+    Ok(())
+}
+
+/// Sets the maximum proposal description length in bytes (admin only)
+pub fn set_max_description_len(env: &Env, admin: Address, len: u32) -> Result<(), SavingsError> {
+    admin.require_auth();
+
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(SavingsError::Unauthorized)?;
+
+    if admin != stored_admin {
+        return Err(SavingsError::Unauthorized);
+    }
+    if len == 0 {
+        return Err(SavingsError::InvalidDescription);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&GovernanceKey::MaxDescriptionLen, &len);
+
+    Ok(())
+}
+
+/// Returns the maximum proposal description length in bytes
+pub fn get_max_description_len(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&GovernanceKey::MaxDescriptionLen)
+        .unwrap_or(DEFAULT_MAX_DESCRIPTION_LEN)
+}
+
+/// Sets the smallest voting power a vote must carry to count (admin only)
+pub fn set_min_vote_weight(env: &Env, admin: Address, weight: u128) -> Result<(), SavingsError> {
+    admin.require_auth();
+
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(SavingsError::Unauthorized)?;
+
+    if admin != stored_admin {
+        return Err(SavingsError::Unauthorized);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&GovernanceKey::MinVoteWeight, &weight);
+
+    Ok(())
+}
+
+/// Returns the minimum vote weight (0 = only zero-weight votes are rejected)
+pub fn get_min_vote_weight(env: &Env) -> u128 {
+    env.storage()
+        .persistent()
+        .get(&GovernanceKey::MinVoteWeight)
+        .unwrap_or(0)
+}
+
+/// Rejects empty descriptions and ones over the configured length
+fn validate_description(env: &Env, description: &String) -> Result<(), SavingsError> {
+    let len = description.len();
+    if len == 0 || len > get_max_description_len(env) {
+        return Err(SavingsError::InvalidDescription);
+    }
+    Ok(())
+}
+
+/// Returns the ID the next created proposal will receive, without reserving it
+pub fn peek_next_proposal_id(env: &Env) -> u64 {
+    get_next_proposal_id(env)
+}
+
 fn get_next_proposal_id(env: &Env) -> u64 {
     env.storage()
         .persistent()
@@ -287,6 +675,19 @@ fn get_next_proposal_id(env: &Env) -> u64 {
         .unwrap_or(1)
 }
 
+/// Returns the next proposal ID, refusing one that is already taken by either
+/// proposal kind (e.g. after `NextProposalId` was reset).
+fn get_unused_proposal_id(env: &Env) -> Result<u64, SavingsError> {
+    let proposal_id = get_next_proposal_id(env);
+    let storage = env.storage().persistent();
+    if storage.has(&GovernanceKey::Proposal(proposal_id))
+        || storage.has(&GovernanceKey::ActionProposal(proposal_id))
+    {
+        return Err(SavingsError::InternalError);
+    }
+    Ok(proposal_id)
+}
+
 /// Casts a weighted vote on a proposal
 pub fn vote(
     env: &Env,
@@ -302,7 +703,7 @@ pub fn vote(
     }
 
     let weight = get_voting_power(env, &voter);
-    if weight == 0 {
+    if weight == 0 || weight < get_min_vote_weight(env) {
         return Err(SavingsError::InsufficientBalance);
     }
 
@@ -348,6 +749,7 @@ pub fn vote(
             .persistent()
             .set(&GovernanceKey::Proposal(proposal_id), &proposal);
         env.storage().persistent().set(&voter_key, &true);
+        record_vote(env, &voter, capped_weight);
 
         emit_vote_cast(env, proposal_id, voter, vote_type, weight);
 
@@ -386,6 +788,7 @@ pub fn vote(
             .persistent()
             .set(&GovernanceKey::ActionProposal(proposal_id), &proposal);
         env.storage().persistent().set(&voter_key, &true);
+        record_vote(env, &voter, capped_weight);
 
         emit_vote_cast(env, proposal_id, voter, vote_type, weight);
 
@@ -395,6 +798,36 @@ pub fn vote(
     Err(SavingsError::PlanNotFound)
 }
 
+/// Returns cumulative proposal, execution and voting counters
+pub fn get_governance_stats(env: &Env) -> GovernanceStats {
+    env.storage()
+        .persistent()
+        .get(&GovernanceKey::Stats)
+        .unwrap_or_default()
+}
+
+fn update_stats(env: &Env, f: impl FnOnce(&mut GovernanceStats)) {
+    let mut stats = get_governance_stats(env);
+    f(&mut stats);
+    env.storage()
+        .persistent()
+        .set(&GovernanceKey::Stats, &stats);
+}
+
+fn record_vote(env: &Env, voter: &Address, weight: u128) {
+    let voter_key = GovernanceKey::HasEverVoted(voter.clone());
+    let first_vote = !env.storage().persistent().has(&voter_key);
+    if first_vote {
+        env.storage().persistent().set(&voter_key, &true);
+    }
+    update_stats(env, |stats| {
+        if first_vote {
+            stats.unique_voters = stats.unique_voters.saturating_add(1);
+        }
+        stats.total_voting_power_cast = stats.total_voting_power_cast.saturating_add(weight);
+    });
+}
+
 /// Checks if a user has already voted on a proposal
 pub fn has_voted(env: &Env, proposal_id: u64, voter: &Address) -> bool {
     let voter_key = GovernanceKey::VoterRecord(proposal_id, voter.clone());
@@ -462,12 +895,31 @@ pub fn queue_proposal(env: &Env, proposal_id: u64) -> Result<(), SavingsError> {
     Err(SavingsError::PlanNotFound)
 }
 
-/// Executes a queued proposal after timelock period
-pub fn execute_proposal(env: &Env, proposal_id: u64) -> Result<(), SavingsError> {
+/// Who executed a proposal and when, once it has been executed
+pub fn get_proposal_execution(env: &Env, proposal_id: u64) -> Option<(Address, u64)> {
+    env.storage()
+        .persistent()
+        .get(&GovernanceKey::Execution(proposal_id))
+}
+
+/// Executes a queued proposal after timelock period, recording `executor`
+/// and the execution time under `GovernanceKey::Execution`
+pub fn execute_proposal(
+    env: &Env,
+    proposal_id: u64,
+    executor: Address,
+) -> Result<(), SavingsError> {
+    executor.require_auth();
+
     let now = env.ledger().timestamp();
     let config = get_voting_config(env)?;
 
     if let Some(mut proposal) = get_action_proposal(env, proposal_id) {
+        // Abstentions alone never authorize an action
+        if proposal.for_votes == 0 {
+            return Err(SavingsError::QuorumNotReached);
+        }
+
         if proposal.queued_time == 0 {
             return Err(SavingsError::TooEarly);
         }
@@ -486,18 +938,30 @@ pub fn execute_proposal(env: &Env, proposal_id: u64) -> Result<(), SavingsError>
         }
 
         proposal.executed = true;
+        env.storage().persistent().set(
+            &GovernanceKey::Execution(proposal_id),
+            &(executor.clone(), now),
+        );
         env.storage()
             .persistent()
             .set(&GovernanceKey::ActionProposal(proposal_id), &proposal);
 
         execute_action(env, &proposal.action)?;
 
+        update_stats(env, |stats| {
+            stats.executed_proposals = stats.executed_proposals.saturating_add(1)
+        });
         emit_proposal_executed(env, proposal_id, now);
 
         return Ok(());
     }
 
     if let Some(mut proposal) = get_proposal(env, proposal_id) {
+        // Abstentions alone never authorize an action
+        if proposal.for_votes == 0 {
+            return Err(SavingsError::QuorumNotReached);
+        }
+
         if proposal.queued_time == 0 {
             return Err(SavingsError::TooEarly);
         }
@@ -516,10 +980,17 @@ pub fn execute_proposal(env: &Env, proposal_id: u64) -> Result<(), SavingsError>
         }
 
         proposal.executed = true;
+        env.storage().persistent().set(
+            &GovernanceKey::Execution(proposal_id),
+            &(executor.clone(), now),
+        );
         env.storage()
             .persistent()
             .set(&GovernanceKey::Proposal(proposal_id), &proposal);
 
+        update_stats(env, |stats| {
+            stats.executed_proposals = stats.executed_proposals.saturating_add(1)
+        });
         emit_proposal_executed(env, proposal_id, now);
 
         return Ok(());
@@ -529,7 +1000,7 @@ pub fn execute_proposal(env: &Env, proposal_id: u64) -> Result<(), SavingsError>
 }
 
 /// Executes a proposal action
-fn execute_action(env: &Env, action: &ProposalAction) -> Result<(), SavingsError> {
+pub(crate) fn execute_action(env: &Env, action: &ProposalAction) -> Result<(), SavingsError> {
     match action {
         ProposalAction::SetFlexiRate(rate) => {
             if *rate < 0 {
@@ -561,6 +1032,14 @@ fn execute_action(env: &Env, action: &ProposalAction) -> Result<(), SavingsError
                 .set(&DataKey::LockRate(*duration), rate);
             Ok(())
         }
+        ProposalAction::SetLockLimits(min, max) => {
+            if *min < 0 || min > max {
+                return Err(SavingsError::InvalidPlanConfig);
+            }
+            env.storage().instance().set(&DataKey::MinLockAmount, min);
+            env.storage().instance().set(&DataKey::MaxLockAmount, max);
+            Ok(())
+        }
         ProposalAction::PauseContract => {
             env.storage().persistent().set(&DataKey::Paused, &true);
             crate::ttl::extend_config_ttl(env, &DataKey::Paused);
@@ -571,6 +1050,14 @@ fn execute_action(env: &Env, action: &ProposalAction) -> Result<(), SavingsError
             crate::ttl::extend_config_ttl(env, &DataKey::Paused);
             Ok(())
         }
+        ProposalAction::SetToken(token) => crate::config::set_token(env, token),
+        ProposalAction::SetPowerSource(source) => {
+            env.storage()
+                .persistent()
+                .set(&GovernanceKey::PowerSource, source);
+            Ok(())
+        }
+        ProposalAction::EmergencyShutdown => crate::config::shut_down(env),
     }
 }
 
@@ -45,7 +45,8 @@ pub fn emit_proposal_created(env: &Env, proposal_id: u64, creator: Address, desc
         description,
     };
 
-    env.events().publish(
+    crate::events::publish(
+        env,
         (symbol_short!("gov"), symbol_short!("created"), creator),
         event,
     );
@@ -59,8 +60,11 @@ pub fn emit_vote_cast(env: &Env, proposal_id: u64, voter: Address, vote_type: u3
         weight,
     };
 
-    env.events()
-        .publish((symbol_short!("gov"), symbol_short!("voted"), voter), event);
+    crate::events::publish(
+        env,
+        (symbol_short!("gov"), symbol_short!("voted"), voter),
+        event,
+    );
 }
 
 pub fn emit_proposal_queued(env: &Env, proposal_id: u64, queued_at: u64) {
@@ -68,8 +72,7 @@ pub fn emit_proposal_queued(env: &Env, proposal_id: u64, queued_at: u64) {
         proposal_id,
         queued_at,
     };
-    env.events()
-        .publish((symbol_short!("gov"), symbol_short!("queued")), event);
+    crate::events::publish(env, (symbol_short!("gov"), symbol_short!("queued")), event);
 }
 
 pub fn emit_proposal_executed(env: &Env, proposal_id: u64, executed_at: u64) {
@@ -77,8 +80,11 @@ pub fn emit_proposal_executed(env: &Env, proposal_id: u64, executed_at: u64) {
         proposal_id,
         executed_at,
     };
-    env.events()
-        .publish((symbol_short!("gov"), symbol_short!("executed")), event);
+    crate::events::publish(
+        env,
+        (symbol_short!("gov"), symbol_short!("executed")),
+        event,
+    );
 }
 
 pub fn emit_proposal_canceled(env: &Env, proposal_id: u64, canceled_at: u64) {
@@ -86,6 +92,9 @@ pub fn emit_proposal_canceled(env: &Env, proposal_id: u64, canceled_at: u64) {
         proposal_id,
         canceled_at,
     };
-    env.events()
-        .publish((symbol_short!("gov"), symbol_short!("canceled")), event);
+    crate::events::publish(
+        env,
+        (symbol_short!("gov"), symbol_short!("canceled")),
+        event,
+    );
 }
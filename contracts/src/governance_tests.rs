@@ -25,7 +25,7 @@ mod governance_tests {
         env.ledger().with_mut(|li| li.timestamp += 11);
         client.queue_proposal(&proposal_id);
         env.ledger().with_mut(|li| li.timestamp += 6);
-        client.execute_proposal(&proposal_id);
+        client.execute_proposal(&proposal_id, &Address::generate(&env));
         let proposal = client.get_proposal(&proposal_id).unwrap();
         assert!(proposal.executed);
     }
@@ -56,13 +56,14 @@ mod governance_tests {
         // Attacker tries to execute before timelock
         env.ledger().with_mut(|li| li.timestamp += 11);
         client.queue_proposal(&proposal_id);
-        let early_exec = client.try_execute_proposal(&proposal_id);
+        let early_exec = client.try_execute_proposal(&proposal_id, &Address::generate(&env));
         assert!(early_exec.is_err());
     }
 
+    use crate::governance::{self, VotingConfig};
     use crate::governance_events::{ProposalCreated, VoteCast};
     use crate::rewards::storage_types::RewardsConfig;
-    use crate::{NesteraContract, NesteraContractClient, PlanType};
+    use crate::{NesteraContract, NesteraContractClient, PlanType, SavingsError};
     use soroban_sdk::symbol_short;
     use soroban_sdk::IntoVal;
     use soroban_sdk::{
@@ -150,6 +151,284 @@ mod governance_tests {
         assert_eq!(config.timelock_duration, 86400);
     }
 
+    #[test]
+    fn test_governance_status_defaults_when_unconfigured() {
+        let (_env, client, _admin) = setup_contract();
+
+        let status = client.get_governance_status();
+        assert!(!status.is_active);
+        assert!(!status.is_configured);
+        assert_eq!(status.config, VotingConfig::default());
+        assert_eq!(status.next_proposal_id, 1);
+        assert_eq!(status.proposal_count, 0);
+    }
+
+    #[test]
+    fn test_governance_status_reflects_initialized_setup() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+        client.activate_governance(&admin);
+
+        let creator = Address::generate(&env);
+        client.initialize_user(&creator);
+        let _ = client.create_savings_plan(&creator, &PlanType::Flexi, &1000);
+        client.create_proposal(&creator, &String::from_str(&env, "First"));
+
+        let status = client.get_governance_status();
+        assert!(status.is_active);
+        assert!(status.is_configured);
+        assert_eq!(status.config, client.get_voting_config());
+        assert_eq!(status.config.quorum, 5000);
+        assert_eq!(status.next_proposal_id, 2);
+        assert_eq!(status.proposal_count, 1);
+    }
+
+    #[test]
+    fn test_peek_next_proposal_id_matches_created_id() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+        let creator = Address::generate(&env);
+        client.initialize_user(&creator);
+        let _ = client.create_savings_plan(&creator, &PlanType::Flexi, &1000);
+
+        let peeked = client.peek_next_proposal_id();
+        assert_eq!(client.peek_next_proposal_id(), peeked);
+        let id = client.create_proposal(&creator, &String::from_str(&env, "Peek"));
+        assert_eq!(id, peeked);
+        assert_eq!(client.peek_next_proposal_id(), peeked + 1);
+    }
+
+    #[test]
+    fn test_init_voting_config_rejects_zero_voting_period() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        let result = client.try_init_voting_config(&admin, &5000, &0, &86400, &100, &10_000);
+        assert!(result.is_err());
+        assert!(client.try_get_voting_config().is_err());
+    }
+
+    #[test]
+    fn test_proposal_voting_window_is_votable() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        client.init_voting_config(&admin, &5000, &1, &86400, &100, &10_000);
+
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        client.initialize_user(&voter);
+        client.create_savings_plan(&voter, &PlanType::Flexi, &1000);
+
+        let description = String::from_str(&env, "Short window");
+        let proposal_id = client.create_proposal(&creator, &description);
+
+        let proposal = client.get_proposal(&proposal_id).unwrap();
+        assert!(proposal.end_time > proposal.start_time);
+
+        client.vote(&proposal_id, &1, &voter);
+        let (for_votes, _, _) = client.get_proposal_votes(&proposal_id);
+        assert_eq!(for_votes, 1000);
+    }
+
+    #[test]
+    fn test_proposal_cooldown_blocks_rapid_creation() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+        client.set_proposal_cooldown(&admin, &3600);
+        assert_eq!(client.get_proposal_cooldown(), 3600);
+
+        let creator = Address::generate(&env);
+        let description = String::from_str(&env, "Spam proposal");
+
+        client.create_proposal(&creator, &description);
+
+        let second = client.try_create_proposal(&creator, &description);
+        assert_eq!(second, Err(Ok(SavingsError::ProposalCooldownActive)));
+
+        // A different creator is unaffected
+        let other = Address::generate(&env);
+        assert!(client.try_create_proposal(&other, &description).is_ok());
+
+        env.ledger().with_mut(|li| li.timestamp += 3600);
+        assert!(client.try_create_proposal(&creator, &description).is_ok());
+    }
+
+    #[test]
+    fn test_set_proposal_cooldown_admin_only() {
+        let (env, client, _admin) = setup_contract();
+        env.mock_all_auths();
+
+        let stranger = Address::generate(&env);
+        let result = client.try_set_proposal_cooldown(&stranger, &3600);
+        assert_eq!(result, Err(Ok(SavingsError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_proposal_description_length_limits() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+        assert_eq!(
+            client.get_max_description_len(),
+            governance::DEFAULT_MAX_DESCRIPTION_LEN
+        );
+        client.set_max_description_len(&admin, &8);
+
+        let creator = Address::generate(&env);
+
+        let at_limit = String::from_str(&env, "12345678");
+        assert!(client.try_create_proposal(&creator, &at_limit).is_ok());
+
+        let over_limit = String::from_str(&env, "123456789");
+        assert_eq!(
+            client.try_create_proposal(&creator, &over_limit),
+            Err(Ok(SavingsError::InvalidDescription))
+        );
+
+        let empty = String::from_str(&env, "");
+        assert_eq!(
+            client.try_create_proposal(&creator, &empty),
+            Err(Ok(SavingsError::InvalidDescription))
+        );
+    }
+
+    #[test]
+    fn test_min_vote_weight_rejects_dust_votes() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+        assert_eq!(client.get_min_vote_weight(), 0);
+        client.set_min_vote_weight(&admin, &1_000);
+
+        let dust = Address::generate(&env);
+        let at_min = Address::generate(&env);
+        client.initialize_user(&dust);
+        client.initialize_user(&at_min);
+        client.create_savings_plan(&dust, &PlanType::Flexi, &999);
+        client.create_savings_plan(&at_min, &PlanType::Flexi, &1_000);
+
+        let creator = Address::generate(&env);
+        let proposal_id = client.create_proposal(&creator, &String::from_str(&env, "Dust"));
+
+        assert_eq!(
+            client.try_vote(&proposal_id, &1, &dust),
+            Err(Ok(SavingsError::InsufficientBalance))
+        );
+        client.vote(&proposal_id, &1, &at_min);
+        assert_eq!(client.get_proposal(&proposal_id).unwrap().for_votes, 1_000);
+
+        let stranger = Address::generate(&env);
+        assert_eq!(
+            client.try_set_min_vote_weight(&stranger, &1),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_reset_proposal_counter_cannot_reuse_id() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+
+        let creator = Address::generate(&env);
+        let description = String::from_str(&env, "Original proposal");
+        let proposal_id = client.create_proposal(&creator, &description);
+
+        // Simulate a corrupted counter pointing back at an existing proposal
+        env.as_contract(&client.address, || {
+            env.storage()
+                .persistent()
+                .set(&governance::GovernanceKey::NextProposalId, &proposal_id);
+        });
+
+        let other = Address::generate(&env);
+        let replacement = String::from_str(&env, "Replacement");
+        assert_eq!(
+            client.try_create_proposal(&other, &replacement),
+            Err(Ok(SavingsError::InternalError))
+        );
+        assert_eq!(
+            client.get_proposal(&proposal_id).unwrap().description,
+            description
+        );
+    }
+
+    #[test]
+    fn test_proposal_result_passed() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+
+        let creator = Address::generate(&env);
+        let description = String::from_str(&env, "Popular proposal");
+        let proposal_id = client.create_proposal(&creator, &description);
+
+        let supporter = Address::generate(&env);
+        client.initialize_user(&supporter);
+        client.create_savings_plan(&supporter, &PlanType::Flexi, &5000);
+        client.vote(&proposal_id, &1, &supporter);
+
+        let opponent = Address::generate(&env);
+        client.initialize_user(&opponent);
+        client.create_savings_plan(&opponent, &PlanType::Flexi, &1000);
+        client.vote(&proposal_id, &2, &opponent);
+
+        // Still open
+        assert_eq!(
+            client.try_get_proposal_result(&proposal_id),
+            Err(Ok(SavingsError::TooEarly))
+        );
+
+        env.ledger().with_mut(|li| li.timestamp += 604800 + 1);
+        let result = client.get_proposal_result(&proposal_id);
+        assert_eq!(result.turnout, 6000);
+        assert_eq!(result.for_votes, 5000);
+        assert_eq!(result.against_votes, 1000);
+        assert_eq!(result.abstain_votes, 0);
+        assert!(result.quorum_met);
+        assert!(result.passed);
+    }
+
+    #[test]
+    fn test_proposal_result_failed_quorum() {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+
+        let creator = Address::generate(&env);
+        let description = String::from_str(&env, "Quiet proposal");
+        let proposal_id = client.create_proposal(&creator, &description);
+
+        let voter = Address::generate(&env);
+        client.initialize_user(&voter);
+        client.create_savings_plan(&voter, &PlanType::Flexi, &3000);
+        client.vote(&proposal_id, &1, &voter);
+
+        env.ledger().with_mut(|li| li.timestamp += 604800 + 1);
+        let result = client.get_proposal_result(&proposal_id);
+        assert_eq!(result.turnout, 3000);
+        assert_eq!(result.for_votes, 3000);
+        assert!(!result.quorum_met);
+        assert!(!result.passed);
+
+        assert_eq!(
+            client.try_get_proposal_result(&999),
+            Err(Ok(SavingsError::PlanNotFound))
+        );
+    }
+
     #[test]
     fn test_create_proposal() {
         let (env, client, admin) = setup_contract();
@@ -1,8 +1,10 @@
-use crate::ensure_not_paused;
 use crate::errors::SavingsError;
+use crate::events;
+use crate::stats::{self, PlanKind};
 use crate::storage_types::{DataKey, GroupSave};
 use crate::ttl;
 use crate::users;
+use crate::{ensure_not_paused, ensure_withdrawals_allowed};
 use soroban_sdk::{Address, Env, String, Vec};
 
 /// Creates a new group savings plan.
@@ -45,6 +47,7 @@ pub fn create_group_save(
     end_time: u64,
 ) -> Result<u64, SavingsError> {
     ensure_not_paused(env)?;
+    crate::config::ensure_plan_type_enabled(env, PlanKind::Group)?;
     // Validate target_amount > 0
     if target_amount <= 0 {
         return Err(SavingsError::InvalidAmount);
@@ -100,6 +103,7 @@ pub fn create_group_save(
     // Store the GroupSave in persistent storage
     let group_key = DataKey::GroupSave(group_id);
     env.storage().persistent().set(&group_key, &new_group);
+    stats::record_plan_opened(env, PlanKind::Group, 0)?;
 
     // Update NextGroupId for the next group creation
     env.storage()
@@ -148,8 +152,7 @@ pub fn create_group_save(
     ttl::extend_plan_ttl(env, &plan_key);
 
     // Emit event for group creation
-    env.events()
-        .publish((soroban_sdk::symbol_short!("grp_new"), creator), group_id);
+    events::emit_group_created(env, creator, group_id);
 
     Ok(group_id)
 }
@@ -334,8 +337,7 @@ pub fn join_group_save(env: &Env, user: Address, group_id: u64) -> Result<(), Sa
     ttl::extend_plan_ttl(env, &plan_key);
 
     // Emit event for joining group
-    env.events()
-        .publish((soroban_sdk::symbol_short!("grp_join"), user), group_id);
+    events::emit_group_joined(env, user, group_id);
 
     Ok(())
 }
@@ -418,6 +420,8 @@ pub fn contribute_to_group_save(
 
     // Save updated group
     env.storage().persistent().set(&group_key, &group);
+    stats::record_principal_added(env, PlanKind::Group, amount)?;
+    adjust_user_balance(env, &user, amount)?;
 
     // Update the user's SavingsPlan to reflect the new balance
     let plan_key = DataKey::SavingsPlan(user.clone(), group_id);
@@ -454,6 +458,7 @@ pub fn contribute_to_group_save(
 
     // Award deposit points
     crate::rewards::storage::award_deposit_points(env, user.clone(), amount)?;
+    stats::issue_deposit_receipt(env, &user, amount)?;
 
     // Extend TTL on contribution
     ttl::extend_group_ttl(env, group_id);
@@ -461,14 +466,26 @@ pub fn contribute_to_group_save(
     ttl::extend_plan_ttl(env, &plan_key);
 
     // Emit event for contribution
-    env.events().publish(
-        (soroban_sdk::symbol_short!("grp_cont"), user, group_id),
-        amount,
-    );
+    events::emit_group_contribution(env, user, group_id, amount);
 
     Ok(())
 }
 
+/// Moves a member's `total_balance` by `delta` as their group contribution
+/// changes
+fn adjust_user_balance(env: &Env, user: &Address, delta: i128) -> Result<(), SavingsError> {
+    if let Some(mut user_data) = users::load_user(env, user) {
+        user_data.total_balance = user_data
+            .total_balance
+            .checked_add(delta)
+            .ok_or(SavingsError::Overflow)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::User(user.clone()), &user_data);
+    }
+    Ok(())
+}
+
 /// VIEW FUNCTION - Gets a member's contribution to a group
 ///
 /// # Arguments
@@ -558,7 +575,7 @@ fn remove_group_from_user_list(
 /// - User is not a member of the group
 /// - Group is already completed
 pub fn break_group_save(env: &Env, user: Address, group_id: u64) -> Result<(), SavingsError> {
-    ensure_not_paused(env)?;
+    ensure_withdrawals_allowed(env)?;
 
     // Ensure user exists
     if !users::user_exists(env, &user) {
@@ -630,6 +647,8 @@ pub fn break_group_save(env: &Env, user: Address, group_id: u64) -> Result<(), S
 
     // Save updated group
     env.storage().persistent().set(&group_key, &group);
+    stats::record_principal_removed(env, PlanKind::Group, user_contribution)?;
+    adjust_user_balance(env, &user, -user_contribution)?;
 
     // Remove user's contribution entry
     env.storage().persistent().remove(&contribution_key);
@@ -645,10 +664,7 @@ pub fn break_group_save(env: &Env, user: Address, group_id: u64) -> Result<(), S
     ttl::extend_group_ttl(env, group_id);
 
     // Emit event for leaving group
-    env.events().publish(
-        (soroban_sdk::symbol_short!("grp_leave"), user, group_id),
-        user_contribution,
-    );
+    events::emit_group_left(env, user, group_id, user_contribution);
 
     Ok(())
 }
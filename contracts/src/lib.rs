@@ -8,13 +8,19 @@ use soroban_sdk::{
 mod autosave;
 mod config;
 mod errors;
+mod events;
 mod flexi;
 mod goal;
 mod governance;
 mod governance_events;
 mod group;
 mod invariants;
+mod loan;
 mod lock;
+mod math;
+mod multisig;
+mod operators;
+mod penalty;
 
 pub mod rewards;
 mod storage_types;
@@ -27,16 +33,23 @@ mod users;
 mod security;
 
 mod rates;
+mod stats;
+mod vesting;
 mod views;
 
 pub use crate::config::Config;
 pub use crate::errors::SavingsError;
+pub use crate::penalty::PenaltySplit;
+pub use crate::stats::PlanKind;
 pub use crate::storage_types::{
-    AutoSave, DataKey, GoalSave, GoalSaveView, GroupSave, GroupSaveView, LockSave, LockSaveView,
-    MintPayload, PlanType, SavingsPlan, User,
+    AutoSave, AutoSaveReceipt, DataKey, GoalSave, GoalSaveView, GroupSave, GroupSaveView,
+    InterestBreakdown, LockSave, LockSaveView, LockState, LockStatusFilter, LockWithdrawResult,
+    MatureAction, MintPayload, PlanStat, PlanType, PlanTypeStats, RoundingMode, SavingsPlan,
+    Solvency, User, UserPrefs,
 };
 pub use crate::strategy::registry::StrategyInfo;
 pub use crate::strategy::routing::{StrategyPosition, StrategyPositionKey};
+pub use crate::vesting::VestingLock;
 
 /// Custom error codes for the contract administration
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -65,9 +78,22 @@ pub(crate) fn ensure_not_paused(env: &Env) -> Result<(), SavingsError> {
         ttl::extend_config_ttl(env, &paused_key);
     }
 
+    if config::is_shutdown(env) {
+        return Err(SavingsError::ContractShutdown);
+    }
+
     config::require_not_paused(env)
 }
 
+/// Guard for withdrawal paths: after an emergency shutdown they stay open
+/// even if the contract is also paused, so users can always exit.
+pub(crate) fn ensure_withdrawals_allowed(env: &Env) -> Result<(), SavingsError> {
+    if config::is_shutdown(env) {
+        return Ok(());
+    }
+    ensure_not_paused(env)
+}
+
 pub(crate) fn calculate_fee(amount: i128, fee_bps: u32) -> Result<i128, SavingsError> {
     if fee_bps == 0 {
         return Ok(0);
@@ -171,8 +197,7 @@ impl NesteraContract {
         // Extend instance TTL
         ttl::extend_instance_ttl(&env);
 
-        env.events()
-            .publish((symbol_short!("init"),), admin_public_key);
+        events::publish(&env, (symbol_short!("init"),), admin_public_key);
     }
 
     pub fn verify_signature(env: Env, payload: MintPayload, signature: BytesN<64>) -> bool {
@@ -198,8 +223,7 @@ impl NesteraContract {
     pub fn mint(env: Env, payload: MintPayload, signature: BytesN<64>) -> i128 {
         Self::verify_signature(env.clone(), payload.clone(), signature);
         let amount = payload.amount;
-        env.events()
-            .publish((symbol_short!("mint"), payload.user), amount);
+        events::publish(&env, (symbol_short!("mint"), payload.user), amount);
         amount
     }
 
@@ -215,6 +239,7 @@ impl NesteraContract {
     ) -> Result<u64, SavingsError> {
         // 1. CHECKS
         ensure_not_paused(&env)?;
+        config::ensure_plan_type_enabled(&env, (&plan_type).into())?;
         invariants::assert_non_negative(initial_deposit)?;
 
         rewards::storage::award_deposit_points(&env, user.clone(), initial_deposit)?;
@@ -223,10 +248,8 @@ impl NesteraContract {
             return Err(SavingsError::InternalError);
         }
 
-        let mut user_data = Self::get_user(env.clone(), user.clone()).unwrap_or(User {
-            total_balance: 0,
-            savings_count: 0,
-        });
+        let mut user_data = Self::get_user(env.clone(), user.clone()).unwrap_or_default();
+        user_data.record_activity(env.ledger().timestamp(), initial_deposit > 0);
 
         // 2. EFFECTS (Using Checked Math)
         user_data.savings_count = user_data
@@ -241,6 +264,8 @@ impl NesteraContract {
 
         let plan_id = user_data.savings_count as u64;
 
+        stats::record_plan_opened(&env, (&plan_type).into(), initial_deposit)?;
+
         let new_plan = SavingsPlan {
             plan_id,
             plan_type,
@@ -262,7 +287,11 @@ impl NesteraContract {
             .set(&DataKey::SavingsPlan(user.clone(), plan_id), &new_plan);
 
         // 3. INTERACTIONS (Events)
-        env.events().publish(
+        if initial_deposit > 0 {
+            stats::issue_deposit_receipt(&env, &user, initial_deposit)?;
+        }
+        events::publish(
+            &env,
             (Symbol::new(&env, "create_plan"), user, plan_id),
             initial_deposit,
         );
@@ -276,11 +305,39 @@ impl NesteraContract {
         users::get_user(&env, &user)
     }
 
+    /// Whether the user has had no deposit or withdrawal for over `threshold_seconds`
+    pub fn is_dormant(env: Env, user: Address, threshold_seconds: u64) -> bool {
+        users::is_dormant(&env, &user, threshold_seconds)
+    }
+
+    /// Filters `user_set` down to the dormant users
+    pub fn list_dormant_users(
+        env: Env,
+        user_set: Vec<Address>,
+        threshold_seconds: u64,
+    ) -> Vec<Address> {
+        users::list_dormant_users(&env, user_set, threshold_seconds)
+    }
+
+    /// Returns the total interest a user has been paid across all withdrawals
+    pub fn get_lifetime_interest(env: Env, user: Address) -> i128 {
+        users::get_lifetime_interest(&env, &user)
+    }
+
     pub fn initialize_user(env: Env, user: Address) -> Result<(), SavingsError> {
         ensure_not_paused(&env)?;
         users::initialize_user(&env, user)
     }
 
+    /// Returns the user's notification preferences (defaults if never set)
+    pub fn get_user_prefs(env: Env, user: Address) -> UserPrefs {
+        users::get_user_prefs(&env, &user)
+    }
+
+    pub fn set_user_prefs(env: Env, user: Address, prefs: UserPrefs) -> Result<(), SavingsError> {
+        users::set_user_prefs(&env, user, prefs)
+    }
+
     pub fn user_exists(env: Env, user: Address) -> bool {
         users::user_exists(&env, &user)
     }
@@ -290,36 +347,529 @@ impl NesteraContract {
         flexi::flexi_deposit(env, user, amount)
     }
 
-    pub fn withdraw_flexi(env: Env, user: Address, amount: i128) -> Result<(), SavingsError> {
-        ensure_not_paused(&env)?;
-        flexi::flexi_withdraw(env, user, amount)
+    /// Deposits into `beneficiary`'s Flexi balance, funded and authorized by `payer`
+    pub fn deposit_flexi_for(
+        env: Env,
+        payer: Address,
+        beneficiary: Address,
+        amount: i128,
+    ) -> Result<(), SavingsError> {
+        ensure_not_paused(&env)?;
+        flexi::flexi_deposit_for(env, payer, beneficiary, amount)
+    }
+
+    pub fn withdraw_flexi(env: Env, user: Address, amount: i128) -> Result<(), SavingsError> {
+        ensure_withdrawals_allowed(&env)?;
+        flexi::flexi_withdraw(env, user, amount)
+    }
+
+    pub fn get_flexi_balance(env: Env, user: Address) -> i128 {
+        flexi::get_flexi_balance(&env, user).unwrap_or(0)
+    }
+
+    // --- Lock Save Logic ---
+
+    pub fn create_lock_save(env: Env, user: Address, amount: i128, duration: u64) -> u64 {
+        ensure_not_paused(&env).unwrap_or_else(|e| panic_with_error!(&env, e));
+        user.require_auth();
+        lock::create_lock_save(&env, user, amount, duration)
+            .unwrap_or_else(|e| panic_with_error!(&env, e))
+    }
+
+    /// Creates a lock maturing at an absolute `maturity_timestamp`
+    pub fn create_lock_save_until(
+        env: Env,
+        user: Address,
+        amount: i128,
+        maturity_timestamp: u64,
+    ) -> Result<u64, SavingsError> {
+        ensure_not_paused(&env)?;
+        user.require_auth();
+        lock::create_lock_save_until(&env, user, amount, maturity_timestamp)
+    }
+
+    /// Creates a lock whose term (and interest accrual) begins at `start_at`
+    pub fn create_lock_save_scheduled(
+        env: Env,
+        user: Address,
+        amount: i128,
+        duration: u64,
+        start_at: u64,
+    ) -> Result<u64, SavingsError> {
+        ensure_not_paused(&env)?;
+        user.require_auth();
+        lock::create_lock_save_scheduled(&env, user, amount, duration, start_at)
+    }
+
+    /// Cancels a lock whose term has not started yet, refunding its principal
+    pub fn cancel_scheduled_lock(
+        env: Env,
+        user: Address,
+        lock_id: u64,
+    ) -> Result<i128, SavingsError> {
+        user.require_auth();
+        lock::cancel_scheduled_lock(&env, user, lock_id)
+    }
+
+    /// Admin-only: opens a lock for `user` at a negotiated `rate` (basis points)
+    pub fn create_lock_save_custom_rate(
+        env: Env,
+        admin: Address,
+        user: Address,
+        amount: i128,
+        duration: u64,
+        rate: u32,
+    ) -> Result<u64, SavingsError> {
+        ensure_not_paused(&env)?;
+        user.require_auth();
+        lock::create_lock_save_custom_rate(&env, admin, user, amount, duration, rate)
+    }
+
+    pub fn set_max_custom_lock_rate(
+        env: Env,
+        admin: Address,
+        rate: u32,
+    ) -> Result<(), SavingsError> {
+        lock::set_max_custom_lock_rate(&env, admin, rate)
+    }
+
+    pub fn get_max_custom_lock_rate(env: Env) -> u32 {
+        lock::get_max_custom_lock_rate(&env)
+    }
+
+    /// Withdraws several locks, reporting success or the error code per lock
+    pub fn withdraw_locks_batch(
+        env: Env,
+        user: Address,
+        lock_ids: Vec<u64>,
+    ) -> Result<Vec<LockWithdrawResult>, SavingsError> {
+        user.require_auth();
+        lock::withdraw_locks_batch(&env, user, lock_ids)
+    }
+
+    /// Sets the post-creation grace window in seconds (admin only)
+    pub fn set_grace_period(env: Env, admin: Address, seconds: u64) -> Result<(), SavingsError> {
+        lock::set_grace_period(&env, admin, seconds)
+    }
+
+    /// Post-creation grace window in seconds (one hour unless configured)
+    pub fn get_grace_period(env: Env) -> u64 {
+        lock::get_grace_period(&env)
+    }
+
+    /// Changes a lock's duration shortly after creation
+    pub fn adjust_lock_duration(
+        env: Env,
+        user: Address,
+        lock_id: u64,
+        new_duration: u64,
+    ) -> Result<(), SavingsError> {
+        user.require_auth();
+        lock::adjust_lock_duration(&env, user, lock_id, new_duration)
+    }
+
+    /// Carves `amount` of principal out of a lock into a new lock with the
+    /// same timing and rate; returns the new lock's ID
+    pub fn split_lock_save(
+        env: Env,
+        user: Address,
+        lock_id: u64,
+        amount: i128,
+    ) -> Result<u64, SavingsError> {
+        user.require_auth();
+        lock::split_lock_save(&env, user, lock_id, amount)
+    }
+
+    /// Merges two locks with the same maturity and rate into a new lock;
+    /// returns the new lock's ID
+    pub fn merge_lock_saves(
+        env: Env,
+        user: Address,
+        lock_id_a: u64,
+        lock_id_b: u64,
+    ) -> Result<u64, SavingsError> {
+        user.require_auth();
+        lock::merge_lock_saves(&env, user, lock_id_a, lock_id_b)
+    }
+
+    /// Chooses what happens to the lock's proceeds when it is settled at maturity
+    pub fn set_lock_mature_action(
+        env: Env,
+        user: Address,
+        lock_id: u64,
+        action: MatureAction,
+    ) -> Result<(), SavingsError> {
+        user.require_auth();
+        lock::set_lock_mature_action(&env, user, lock_id, action)
+    }
+
+    /// The lock's mature action, `Withdraw` unless one was chosen
+    pub fn get_lock_mature_action(env: Env, lock_id: u64) -> MatureAction {
+        lock::get_lock_mature_action(&env, lock_id)
+    }
+
+    /// Donates `bps` of the lock's interest to a charity on withdrawal;
+    /// `None` clears the designation
+    pub fn set_lock_charity(
+        env: Env,
+        user: Address,
+        lock_id: u64,
+        charity: Option<(Address, u32)>,
+    ) -> Result<(), SavingsError> {
+        user.require_auth();
+        lock::set_lock_charity(&env, user, lock_id, charity)
+    }
+
+    pub fn get_lock_charity(env: Env, lock_id: u64) -> Option<(Address, u32)> {
+        lock::get_lock_charity(&env, lock_id)
+    }
+
+    /// Total interest donated to `charity` through lock interest payouts
+    pub fn get_charity_donations(env: Env, charity: Address) -> i128 {
+        lock::get_charity_donations(&env, charity)
+    }
+
+    /// Settles a matured lock per its mature action (callable by keepers)
+    pub fn settle_matured(env: Env, lock_id: u64) -> Result<i128, SavingsError> {
+        lock::settle_matured(&env, lock_id)
+    }
+
+    /// Approves `operator` (e.g. a vault contract) to create and withdraw
+    /// locks for `user` without the user signing each call
+    pub fn approve_operator(
+        env: Env,
+        user: Address,
+        operator: Address,
+    ) -> Result<(), SavingsError> {
+        operators::approve_operator(&env, user, operator)
+    }
+
+    pub fn revoke_operator(env: Env, user: Address, operator: Address) {
+        operators::revoke_operator(&env, user, operator)
+    }
+
+    pub fn is_operator(env: Env, user: Address, operator: Address) -> bool {
+        operators::is_operator(&env, &user, &operator)
+    }
+
+    /// Creates a lock for `user`, authorized by an approved operator
+    pub fn create_lock_save_for(
+        env: Env,
+        operator: Address,
+        user: Address,
+        amount: i128,
+        duration: u64,
+    ) -> Result<u64, SavingsError> {
+        operators::require_operator(&env, &user, &operator)?;
+        lock::create_lock_save(&env, user, amount, duration)
+    }
+
+    /// Withdraws a matured lock of `user`, authorized by an approved operator
+    pub fn withdraw_lock_save_for(
+        env: Env,
+        operator: Address,
+        user: Address,
+        lock_id: u64,
+    ) -> Result<i128, SavingsError> {
+        operators::require_operator(&env, &user, &operator)?;
+        lock::withdraw_lock_save(&env, user, lock_id)
+    }
+
+    pub fn withdraw_lock_save(env: Env, user: Address, lock_id: u64) -> i128 {
+        ensure_withdrawals_allowed(&env).unwrap_or_else(|e| panic_with_error!(&env, e));
+        user.require_auth();
+        lock::withdraw_lock_save(&env, user, lock_id).unwrap_or_else(|e| panic_with_error!(&env, e))
+    }
+
+    /// Withdraws a matured lock, returning `(principal, interest)` instead of the total
+    pub fn withdraw_lock_save_detailed(
+        env: Env,
+        user: Address,
+        lock_id: u64,
+    ) -> Result<(i128, i128), SavingsError> {
+        user.require_auth();
+        lock::withdraw_lock_save_detailed(&env, user, lock_id)
+    }
+
+    /// Pays out a matured lock's interest and re-locks its principal for another term
+    pub fn claim_interest_only(
+        env: Env,
+        user: Address,
+        lock_id: u64,
+    ) -> Result<i128, SavingsError> {
+        user.require_auth();
+        lock::claim_interest_only(&env, user, lock_id)
+    }
+
+    /// Borrows from the interest reserve against a lock, up to the loan LTV
+    /// of its principal; the lock can't be withdrawn until repaid
+    pub fn borrow_against_lock(
+        env: Env,
+        user: Address,
+        lock_id: u64,
+        amount: i128,
+    ) -> Result<i128, SavingsError> {
+        user.require_auth();
+        loan::borrow_against_lock(&env, user, lock_id, amount)
+    }
+
+    /// Repays a lock-backed loan from the user's Flexi balance
+    pub fn repay_loan(
+        env: Env,
+        user: Address,
+        lock_id: u64,
+        amount: i128,
+    ) -> Result<i128, SavingsError> {
+        user.require_auth();
+        loan::repay_loan(&env, user, lock_id, amount)
+    }
+
+    pub fn get_loan_debt(env: Env, lock_id: u64) -> i128 {
+        loan::get_loan_debt(&env, lock_id)
+    }
+
+    /// Sets the maximum loan-to-value ratio for lock-backed loans (admin only)
+    pub fn set_loan_ltv(env: Env, admin: Address, ltv_bps: u32) -> Result<(), SavingsError> {
+        loan::set_loan_ltv(&env, admin, ltv_bps)
+    }
+
+    pub fn get_loan_ltv(env: Env) -> u32 {
+        loan::get_loan_ltv(&env)
+    }
+
+    /// Sets the annual interest rate charged on lock-backed loans (admin only)
+    pub fn set_borrow_rate(env: Env, admin: Address, rate_bps: u32) -> Result<(), SavingsError> {
+        loan::set_borrow_rate(&env, admin, rate_bps)
+    }
+
+    pub fn get_borrow_rate(env: Env) -> u32 {
+        loan::get_borrow_rate(&env)
+    }
+
+    /// Borrow interest owed on a lock's loan, netted from its payout at withdrawal
+    pub fn get_loan_interest(env: Env, lock_id: u64) -> Result<i128, SavingsError> {
+        loan::get_loan_interest(&env, lock_id)
+    }
+
+    /// Withdraws a matured lock, reporting principal and interest as two events.
+    ///
+    /// Returns `(principal, interest)`.
+    pub fn withdraw_lock_save_split(
+        env: Env,
+        user: Address,
+        lock_id: u64,
+    ) -> Result<(i128, i128), SavingsError> {
+        user.require_auth();
+        lock::withdraw_lock_save_split(&env, user, lock_id)
+    }
+
+    pub fn get_lock_save(env: Env, lock_id: u64) -> Option<LockSave> {
+        lock::get_lock_save(&env, lock_id)
+    }
+
+    pub fn check_matured_lock(env: Env, lock_id: u64) -> bool {
+        lock::check_matured_lock(&env, lock_id)
+    }
+
+    /// Sets the rollover rate of a matured, unwithdrawn lock (admin only)
+    pub fn adjust_matured_lock_rate(
+        env: Env,
+        caller: Address,
+        lock_id: u64,
+        new_rate: u32,
+    ) -> Result<(), SavingsError> {
+        lock::adjust_matured_lock_rate(&env, caller, lock_id, new_rate)
+    }
+
+    /// Rate set through `adjust_matured_lock_rate`, if any
+    pub fn get_matured_lock_rate(env: Env, lock_id: u64) -> Option<u32> {
+        lock::get_matured_lock_rate(&env, lock_id)
+    }
+
+    /// Whether a lock's term has begun (false for scheduled locks before `start_at`)
+    pub fn is_lock_started(env: Env, lock_id: u64) -> bool {
+        lock::is_started(&env, lock_id)
+    }
+
+    /// Single lifecycle status for a lock (`None` if not found)
+    pub fn get_lock_state(env: Env, lock_id: u64) -> Option<LockState> {
+        lock::get_lock_state(&env, lock_id)
+    }
+
+    /// Interest accrued on a lock so far (0 once withdrawn, `None` if not found)
+    pub fn preview_lock_interest(env: Env, lock_id: u64) -> Option<i128> {
+        lock::preview_lock_interest(&env, lock_id)
+    }
+
+    /// Annualized return in basis points the lock pays when held to maturity
+    pub fn get_effective_apy(env: Env, lock_id: u64) -> Result<u32, SavingsError> {
+        lock::get_effective_apy(&env, lock_id)
+    }
+
+    /// Projected principal plus interest of a lock at `at_timestamp`
+    pub fn project_lock_value(
+        env: Env,
+        lock_id: u64,
+        at_timestamp: u64,
+    ) -> Result<i128, SavingsError> {
+        lock::project_lock_value(&env, lock_id, at_timestamp)
+    }
+
+    /// Expected principal plus interest across the user's active locks at maturity
+    pub fn get_total_projected_payout(env: Env, user: Address) -> Result<i128, SavingsError> {
+        lock::get_total_projected_payout(&env, &user)
+    }
+
+    /// Seconds until the lock matures (0 once matured, `None` if not found)
+    pub fn time_to_maturity(env: Env, lock_id: u64) -> Option<u64> {
+        lock::time_to_maturity(&env, lock_id)
+    }
+
+    /// Whole days until the lock matures (0 once matured, `None` if not found)
+    pub fn days_to_maturity(env: Env, lock_id: u64) -> Option<u64> {
+        lock::days_to_maturity(&env, lock_id)
+    }
+
+    /// Interest the user has accrued so far, broken down by plan type
+    pub fn get_interest_breakdown(
+        env: Env,
+        user: Address,
+    ) -> Result<InterestBreakdown, SavingsError> {
+        views::get_interest_breakdown(&env, user)
+    }
+
+    /// Whether the user's `total_balance` and `savings_count` agree with
+    /// their flexi balance and open plans
+    pub fn verify_invariants(env: Env, user: Address) -> Result<bool, SavingsError> {
+        views::verify_invariants(&env, user)
+    }
+
+    /// Returns the user's matured locks that have not been withdrawn yet
+    pub fn get_withdrawable_locks(env: Env, user: Address) -> Vec<u64> {
+        lock::get_withdrawable_locks(&env, &user)
+    }
+
+    /// Returns the user's lock IDs matching `status`, evaluated at the current time
+    pub fn get_user_locks_by_status(env: Env, user: Address, status: LockStatusFilter) -> Vec<u64> {
+        lock::get_user_locks_by_status(&env, &user, status)
+    }
+
+    pub fn get_user_lock_saves(env: Env, user: Address) -> Vec<u64> {
+        lock::get_user_lock_saves(&env, &user)
+    }
+
+    /// All of a user's locks in full; fails with `ResultTooLarge` past 50 locks
+    pub fn get_user_lock_saves_detailed(
+        env: Env,
+        user: Address,
+    ) -> Result<Vec<LockSave>, SavingsError> {
+        lock::get_user_lock_saves_detailed(&env, &user)
+    }
+
+    /// A page of at most 50 of a user's locks, starting at position `start`
+    pub fn get_user_lock_saves_paged(
+        env: Env,
+        user: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<LockSave> {
+        lock::get_user_lock_saves_paged(&env, &user, start, limit)
+    }
+
+    /// Admin-only: sets the smallest accepted Flexi deposit (`None` = no minimum)
+    pub fn set_min_flexi_deposit(
+        env: Env,
+        admin: Address,
+        min: Option<i128>,
+    ) -> Result<(), SavingsError> {
+        flexi::set_min_flexi_deposit(&env, admin, min)
+    }
+
+    pub fn get_min_flexi_deposit(env: Env) -> Option<i128> {
+        flexi::get_min_flexi_deposit(&env)
+    }
+
+    /// Admin-only: caps the interest any single lock pays out (`None` = no cap)
+    pub fn set_max_interest_per_lock(
+        env: Env,
+        admin: Address,
+        cap: Option<i128>,
+    ) -> Result<(), SavingsError> {
+        lock::set_max_interest_per_lock(&env, admin, cap)
+    }
+
+    /// Admin-only: caps the principal one user may hold across active locks
+    /// (`None` = no cap)
+    pub fn set_max_total_locked_per_user(
+        env: Env,
+        admin: Address,
+        cap: Option<i128>,
+    ) -> Result<(), SavingsError> {
+        lock::set_max_total_locked_per_user(&env, admin, cap)
+    }
+
+    pub fn get_max_total_locked_per_user(env: Env) -> Option<i128> {
+        lock::get_max_total_locked_per_user(&env)
+    }
+
+    /// Principal the user holds across locks that are not yet withdrawn
+    pub fn get_total_locked(env: Env, user: Address) -> Result<i128, SavingsError> {
+        lock::get_total_locked(&env, &user)
+    }
+
+    /// Principal-weighted average rate (bps) across the user's active locks
+    pub fn get_weighted_avg_rate(env: Env, user: Address) -> u32 {
+        lock::get_weighted_avg_rate(&env, &user)
+    }
+
+    pub fn get_max_interest_per_lock(env: Env) -> Option<i128> {
+        lock::get_max_interest_per_lock(&env)
+    }
+
+    /// Returns the ID the next `create_lock_save` will assign
+    pub fn peek_next_lock_id(env: Env) -> u64 {
+        lock::peek_next_lock_id(&env)
     }
 
-    pub fn get_flexi_balance(env: Env, user: Address) -> i128 {
-        flexi::get_flexi_balance(&env, user).unwrap_or(0)
+    /// Admin-only: deletes withdrawn locks to reclaim storage rent
+    pub fn reclaim_lock_storage(
+        env: Env,
+        admin: Address,
+        lock_ids: Vec<u64>,
+    ) -> Result<u32, SavingsError> {
+        lock::reclaim_lock_storage(&env, admin, lock_ids)
     }
 
-    // --- Lock Save Logic ---
+    /// Returns the (min, max) principal accepted for new lock saves
+    pub fn get_lock_limits(env: Env) -> (i128, i128) {
+        lock::get_lock_limits(&env)
+    }
 
-    pub fn create_lock_save(env: Env, user: Address, amount: i128, duration: u64) -> u64 {
-        ensure_not_paused(&env).unwrap_or_else(|e| panic_with_error!(&env, e));
+    /// Creates a lock whose principal vests linearly after `cliff` seconds,
+    /// fully unlocking after `duration` seconds.
+    pub fn create_vesting_lock(
+        env: Env,
+        user: Address,
+        amount: i128,
+        cliff: u64,
+        duration: u64,
+    ) -> Result<u64, SavingsError> {
         user.require_auth();
-        lock::create_lock_save(&env, user, amount, duration)
-            .unwrap_or_else(|e| panic_with_error!(&env, e))
+        vesting::create_vesting_lock(&env, user, amount, cliff, duration)
     }
 
-    pub fn withdraw_lock_save(env: Env, user: Address, lock_id: u64) -> i128 {
-        ensure_not_paused(&env).unwrap_or_else(|e| panic_with_error!(&env, e));
+    /// Claims the vested-but-unclaimed portion of a vesting lock
+    pub fn claim_vested(env: Env, user: Address, lock_id: u64) -> Result<i128, SavingsError> {
         user.require_auth();
-        lock::withdraw_lock_save(&env, user, lock_id).unwrap_or_else(|e| panic_with_error!(&env, e))
+        vesting::claim_vested(&env, user, lock_id)
     }
 
-    pub fn check_matured_lock(env: Env, lock_id: u64) -> bool {
-        lock::check_matured_lock(&env, lock_id)
+    pub fn get_vesting_lock(env: Env, lock_id: u64) -> Option<VestingLock> {
+        vesting::get_vesting_lock(&env, lock_id)
     }
 
-    pub fn get_user_lock_saves(env: Env, user: Address) -> Vec<u64> {
-        lock::get_user_lock_saves(&env, &user)
+    pub fn get_user_vesting_locks(env: Env, user: Address) -> Vec<u64> {
+        vesting::get_user_vesting_locks(&env, &user)
     }
 
     // ========== Goal Save Functions ==========
@@ -343,16 +893,56 @@ impl NesteraContract {
     }
 
     pub fn withdraw_completed_goal_save(env: Env, user: Address, goal_id: u64) -> i128 {
-        ensure_not_paused(&env).unwrap_or_else(|e| panic_with_error!(&env, e));
+        ensure_withdrawals_allowed(&env).unwrap_or_else(|e| panic_with_error!(&env, e));
         goal::withdraw_completed_goal_save(&env, user, goal_id)
             .unwrap_or_else(|e| panic_with_error!(&env, e))
     }
 
     pub fn break_goal_save(env: Env, user: Address, goal_id: u64) -> i128 {
-        ensure_not_paused(&env).unwrap_or_else(|e| panic_with_error!(&env, e));
+        ensure_withdrawals_allowed(&env).unwrap_or_else(|e| panic_with_error!(&env, e));
         goal::break_goal_save(&env, user, goal_id).unwrap_or_else(|e| panic_with_error!(&env, e))
     }
 
+    /// Contributes to another user's goal. Any registered user may contribute;
+    /// their share is paid back proportionally when the goal is withdrawn.
+    pub fn contribute_shared_goal(
+        env: Env,
+        contributor: Address,
+        goal_id: u64,
+        amount: i128,
+    ) -> Result<(), SavingsError> {
+        goal::contribute_shared_goal(&env, contributor, goal_id, amount)
+    }
+
+    /// Returns the net principal `user` has contributed to the goal
+    pub fn get_contributor_share(
+        env: Env,
+        goal_id: u64,
+        user: Address,
+    ) -> Result<i128, SavingsError> {
+        goal::get_contributor_share(&env, goal_id, &user)
+    }
+
+    /// Sets the seconds between reminders for the goal (0 disables them)
+    pub fn set_goal_reminder_interval(
+        env: Env,
+        user: Address,
+        goal_id: u64,
+        interval: u64,
+    ) -> Result<(), SavingsError> {
+        goal::set_goal_reminder_interval(&env, user, goal_id, interval)
+    }
+
+    /// The goal's `(interval, last_reminded)`, if reminders are turned on
+    pub fn get_goal_reminder(env: Env, goal_id: u64) -> Option<(u64, u64)> {
+        goal::get_goal_reminder(&env, goal_id)
+    }
+
+    /// Publishes `remind` events for the goals that are due one (keeper call)
+    pub fn emit_goal_reminders(env: Env, goal_ids: Vec<u64>) -> Result<Vec<u64>, SavingsError> {
+        goal::emit_goal_reminders(&env, goal_ids)
+    }
+
     pub fn get_goal_save_detail(env: Env, goal_id: u64) -> GoalSave {
         goal::get_goal_save(&env, goal_id)
             .unwrap_or_else(|| panic_with_error!(&env, SavingsError::PlanNotFound))
@@ -409,7 +999,7 @@ impl NesteraContract {
     }
 
     pub fn break_group_save(env: Env, user: Address, group_id: u64) -> Result<(), SavingsError> {
-        ensure_not_paused(&env)?;
+        ensure_withdrawals_allowed(&env)?;
         group::break_group_save(&env, user, group_id)
     }
 
@@ -428,8 +1018,7 @@ impl NesteraContract {
             }
         }
         env.storage().instance().set(&DataKey::Admin, &new_admin);
-        env.events()
-            .publish((symbol_short!("set_admin"),), new_admin);
+        events::publish(&env, (symbol_short!("set_admin"),), new_admin);
         Ok(())
     }
 
@@ -454,6 +1043,24 @@ impl NesteraContract {
         rates::set_lock_rate(&env, caller, duration_days, rate)
     }
 
+    /// Sets how fractional interest is rounded (admin or governance)
+    pub fn set_rounding_mode(
+        env: Env,
+        caller: Address,
+        mode: RoundingMode,
+    ) -> Result<(), SavingsError> {
+        rates::set_rounding_mode(&env, caller, mode)
+    }
+
+    /// Sets the flexi compounding interval in seconds (0 = continuous)
+    pub fn set_flexi_compound_interval(
+        env: Env,
+        caller: Address,
+        interval_seconds: u64,
+    ) -> Result<(), SavingsError> {
+        rates::set_flexi_compound_interval(&env, caller, interval_seconds)
+    }
+
     pub fn set_early_break_fee_bps(env: Env, bps: u32) -> Result<(), SavingsError> {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
@@ -463,17 +1070,27 @@ impl NesteraContract {
         env.storage()
             .instance()
             .set(&DataKey::EarlyBreakFeeBps, &bps);
-        env.events().publish((symbol_short!("set_brk"),), bps);
+        events::publish(&env, (symbol_short!("set_brk"),), bps);
         Ok(())
     }
 
+    /// Sets how early-withdrawal penalties are split between the fee pool,
+    /// the interest reserve and remaining savers (shares must sum to 10_000)
+    pub fn set_penalty_split(
+        env: Env,
+        admin: Address,
+        split: PenaltySplit,
+    ) -> Result<(), SavingsError> {
+        penalty::set_penalty_split(&env, admin, split)
+    }
+
     pub fn set_fee_recipient(env: Env, recipient: Address) -> Result<(), SavingsError> {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
         env.storage()
             .instance()
             .set(&DataKey::FeeRecipient, &recipient);
-        env.events().publish((symbol_short!("set_fee"),), recipient);
+        events::publish(&env, (symbol_short!("set_fee"),), recipient);
         Ok(())
     }
 
@@ -484,30 +1101,65 @@ impl NesteraContract {
             return Err(SavingsError::InvalidAmount);
         }
         env.storage().instance().set(&DataKey::PlatformFee, &bps);
-        env.events().publish((symbol_short!("set_pfee"),), bps);
+        events::publish(&env, (symbol_short!("set_pfee"),), bps);
         Ok(())
     }
 
     pub fn pause(env: Env, caller: Address) -> Result<(), SavingsError> {
         caller.require_auth();
         governance::validate_admin_or_governance(&env, &caller)?;
+        multisig::ensure_single_admin_allowed(&env)?;
 
         env.storage().persistent().set(&DataKey::Paused, &true);
         ttl::extend_config_ttl(&env, &DataKey::Paused);
-        env.events().publish((symbol_short!("pause"), caller), ());
+        events::publish(&env, (symbol_short!("pause"), caller), ());
         Ok(())
     }
 
     pub fn unpause(env: Env, caller: Address) -> Result<(), SavingsError> {
         caller.require_auth();
         governance::validate_admin_or_governance(&env, &caller)?;
+        multisig::ensure_single_admin_allowed(&env)?;
 
         env.storage().persistent().set(&DataKey::Paused, &false);
         ttl::extend_config_ttl(&env, &DataKey::Paused);
-        env.events().publish((symbol_short!("unpause"), caller), ());
+        events::publish(&env, (symbol_short!("unpause"), caller), ());
         Ok(())
     }
 
+    /// Requires `threshold` of `signers` to confirm rate changes and pausing
+    /// (admin only; a threshold of 0 turns confirmations off)
+    pub fn configure_admin_multisig(
+        env: Env,
+        admin: Address,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), SavingsError> {
+        multisig::configure_admin_multisig(&env, admin, signers, threshold)
+    }
+
+    /// Proposes an admin action and returns the hash other signers confirm
+    pub fn propose_admin_action(
+        env: Env,
+        signer: Address,
+        action: governance::ProposalAction,
+    ) -> Result<BytesN<32>, SavingsError> {
+        multisig::propose_admin_action(&env, signer, action)
+    }
+
+    /// Confirms a pending admin action; returns `true` once it has executed
+    pub fn confirm_admin_action(
+        env: Env,
+        signer: Address,
+        action_hash: BytesN<32>,
+    ) -> Result<bool, SavingsError> {
+        multisig::confirm_admin_action(&env, signer, action_hash)
+    }
+
+    pub fn get_admin_approvals(env: Env, action_hash: BytesN<32>) -> Vec<Address> {
+        multisig::get_admin_approvals(&env, action_hash)
+    }
+
     // ========== Emergency Functions ==========
 
     /// Emergency withdraw - allows governance to force withdraw all funds from a strategy
@@ -551,13 +1203,13 @@ impl NesteraContract {
                 if balance > 0 {
                     // Update flexi balance to 0
                     env.storage().persistent().set(&flexi_key, &0i128);
+                    stats::record_plan_closed(&env, PlanKind::Flexi, balance)?;
 
                     // Update user total balance
                     let user_key = DataKey::User(user.clone());
-                    if let Some(mut user_data) =
-                        env.storage().persistent().get::<DataKey, User>(&user_key)
-                    {
+                    if let Some(mut user_data) = users::load_user(&env, &user) {
                         user_data.total_balance = user_data.total_balance.saturating_sub(balance);
+                        user_data.record_activity(env.ledger().timestamp(), false);
                         env.storage().persistent().set(&user_key, &user_data);
                     }
                 }
@@ -573,15 +1225,16 @@ impl NesteraContract {
                         return Err(SavingsError::AlreadyWithdrawn);
                     }
                     let amount = lock.amount;
+                    crate::lock::rebook_committed_interest(&env, Some(&lock), None)?;
                     lock.is_withdrawn = true;
                     env.storage().persistent().set(&lock_key, &lock);
+                    stats::record_plan_closed(&env, PlanKind::Lock, amount)?;
 
                     // Update user total balance
                     let user_key = DataKey::User(user.clone());
-                    if let Some(mut user_data) =
-                        env.storage().persistent().get::<DataKey, User>(&user_key)
-                    {
+                    if let Some(mut user_data) = users::load_user(&env, &user) {
                         user_data.total_balance = user_data.total_balance.saturating_sub(amount);
+                        user_data.record_activity(env.ledger().timestamp(), false);
                         env.storage().persistent().set(&user_key, &user_data);
                     }
                     amount
@@ -601,13 +1254,13 @@ impl NesteraContract {
                     let amount = goal.current_amount;
                     goal.is_withdrawn = true;
                     env.storage().persistent().set(&goal_key, &goal);
+                    stats::record_plan_closed(&env, PlanKind::Goal, amount)?;
 
                     // Update user total balance
                     let user_key = DataKey::User(user.clone());
-                    if let Some(mut user_data) =
-                        env.storage().persistent().get::<DataKey, User>(&user_key)
-                    {
+                    if let Some(mut user_data) = users::load_user(&env, &user) {
                         user_data.total_balance = user_data.total_balance.saturating_sub(amount);
+                        user_data.record_activity(env.ledger().timestamp(), false);
                         env.storage().persistent().set(&user_key, &user_data);
                     }
                     amount
@@ -639,14 +1292,14 @@ impl NesteraContract {
                         // Update group current amount
                         group.current_amount = group.current_amount.saturating_sub(contribution);
                         env.storage().persistent().set(&group_key, &group);
+                        stats::record_principal_removed(&env, PlanKind::Group, contribution)?;
 
                         // Update user total balance
                         let user_key = DataKey::User(user.clone());
-                        if let Some(mut user_data) =
-                            env.storage().persistent().get::<DataKey, User>(&user_key)
-                        {
+                        if let Some(mut user_data) = users::load_user(&env, &user) {
                             user_data.total_balance =
                                 user_data.total_balance.saturating_sub(contribution);
+                            user_data.record_activity(env.ledger().timestamp(), false);
                             env.storage().persistent().set(&user_key, &user_data);
                         }
                     }
@@ -662,7 +1315,8 @@ impl NesteraContract {
         ttl::extend_config_ttl(&env, &disabled_key);
 
         // 5. Emit event
-        env.events().publish(
+        events::publish(
+            &env,
             (Symbol::new(&env, "emergency_withdraw"), user, plan_id),
             withdrawn_amount,
         );
@@ -680,6 +1334,16 @@ impl NesteraContract {
     }
 
     // --- Remaining views and utilities ---
+    /// Returns the most recently issued deposit receipt number
+    pub fn get_deposit_seq(env: Env) -> u64 {
+        stats::get_deposit_seq(&env)
+    }
+
+    /// Returns plan counts and outstanding principal broken down by plan type
+    pub fn get_plan_type_stats(env: Env) -> PlanTypeStats {
+        stats::get_plan_type_stats(&env)
+    }
+
     pub fn get_savings_plan(env: Env, user: Address, plan_id: u64) -> Option<SavingsPlan> {
         env.storage()
             .persistent()
@@ -714,6 +1378,30 @@ impl NesteraContract {
         rates::get_lock_rate(&env, duration_days)
     }
 
+    pub fn get_flexi_compound_interval(env: Env) -> u64 {
+        rates::get_flexi_compound_interval(&env)
+    }
+
+    /// Applies accrued flexi interest (or custody fees at a negative rate) to
+    /// the user's balance and returns the signed amount applied
+    pub fn accrue_flexi_interest(env: Env, user: Address) -> Result<i128, SavingsError> {
+        ensure_not_paused(&env)?;
+        if !users::user_exists(&env, &user) {
+            return Err(SavingsError::UserNotFound);
+        }
+        flexi::accrue_flexi(&env, &user)
+    }
+
+    /// Interest a flexi balance would earn over `duration_seconds` under the
+    /// current rate and compounding interval
+    pub fn preview_flexi_interest(env: Env, balance: i128, duration_seconds: u64) -> i128 {
+        rates::calculate_flexi_accrued_interest(&env, balance, duration_seconds)
+    }
+
+    pub fn get_rounding_mode(env: Env) -> RoundingMode {
+        rates::get_rounding_mode(&env)
+    }
+
     pub fn get_early_break_fee_bps(env: Env) -> u32 {
         env.storage()
             .instance()
@@ -732,6 +1420,46 @@ impl NesteraContract {
             .unwrap_or(0)
     }
 
+    pub fn get_penalty_split(env: Env) -> PenaltySplit {
+        penalty::get_penalty_split(&env)
+    }
+
+    /// Penalties set aside to fund interest payouts
+    pub fn get_interest_reserve(env: Env) -> i128 {
+        penalty::get_interest_reserve(&env)
+    }
+
+    /// Sets the reserve level below which a `lowresv` event is emitted
+    /// (admin only; `None` disables the warning)
+    pub fn set_reserve_warning_threshold(
+        env: Env,
+        admin: Address,
+        threshold: Option<i128>,
+    ) -> Result<(), SavingsError> {
+        penalty::set_reserve_warning_threshold(&env, admin, threshold)
+    }
+
+    pub fn get_reserve_warning_threshold(env: Env) -> Option<i128> {
+        penalty::get_reserve_warning_threshold(&env)
+    }
+
+    /// Credits tokens of the configured asset transferred directly to the
+    /// contract to the interest reserve (admin only). Returns the amount credited.
+    pub fn sync_reserve(env: Env, admin: Address) -> Result<i128, SavingsError> {
+        penalty::sync_reserve(&env, admin)
+    }
+
+    /// The contract's balance of the configured token next to its principal
+    /// and interest liabilities and the interest reserve
+    pub fn get_solvency(env: Env) -> Result<Solvency, SavingsError> {
+        stats::get_solvency(&env)
+    }
+
+    /// Penalties accumulated for distribution to remaining savers
+    pub fn get_staker_pool(env: Env) -> i128 {
+        penalty::get_staker_pool(&env)
+    }
+
     pub fn get_protocol_fee_balance(env: Env, recipient: Address) -> i128 {
         env.storage()
             .persistent()
@@ -807,6 +1535,24 @@ impl NesteraContract {
         rewards::config::get_rewards_config(&env)
     }
 
+    /// Reward tier reached through lifetime deposit volume
+    pub fn get_reward_tier(env: Env, user: Address) -> rewards::storage_types::RewardTier {
+        rewards::storage::get_reward_tier(&env, user)
+    }
+
+    /// Sets the lifetime deposit thresholds for Silver and Gold (admin only)
+    pub fn set_tier_thresholds(
+        env: Env,
+        admin: Address,
+        thresholds: rewards::storage_types::TierThresholds,
+    ) -> Result<(), SavingsError> {
+        rewards::config::set_tier_thresholds(&env, admin, thresholds)
+    }
+
+    pub fn get_tier_thresholds(env: Env) -> rewards::storage_types::TierThresholds {
+        rewards::config::get_tier_thresholds(&env)
+    }
+
     pub fn get_user_rewards(env: Env, user: Address) -> rewards::storage_types::UserRewards {
         rewards::storage::get_user_rewards(&env, user)
     }
@@ -824,6 +1570,12 @@ impl NesteraContract {
         rewards::ranking::get_top_users(&env, limit)
     }
 
+    /// Read-only: sorts up to 100 candidate addresses by lifetime deposits
+    /// and returns the top `limit`
+    pub fn get_top_savers(env: Env, candidates: Vec<Address>, limit: u32) -> Vec<(Address, i128)> {
+        rewards::ranking::get_top_savers(&env, candidates, limit)
+    }
+
     /// Gets the rank of a specific user (1-indexed)
     /// Returns 0 if user has no points or is not ranked
     /// Read-only - no state mutation
@@ -848,6 +1600,11 @@ impl NesteraContract {
         rewards::redemption::redeem_points(&env, user, amount)
     }
 
+    /// Early-withdrawal penalty discount (bps) the user has bought with points
+    pub fn get_penalty_discount(env: Env, user: Address) -> u32 {
+        rewards::redemption::get_penalty_discount(&env, &user)
+    }
+
     // ========== AutoSave Functions ==========
 
     /// Creates a new AutoSave schedule for recurring Flexi deposits
@@ -862,12 +1619,76 @@ impl NesteraContract {
         autosave::create_autosave(&env, user, amount, interval_seconds, start_time)
     }
 
+    /// Same as `create_autosave`, but returns the schedule's ID, first
+    /// execution time and interval in one receipt
+    pub fn create_autosave_v2(
+        env: Env,
+        user: Address,
+        amount: i128,
+        interval_seconds: u64,
+        start_time: u64,
+    ) -> Result<AutoSaveReceipt, SavingsError> {
+        ensure_not_paused(&env)?;
+        autosave::create_autosave_v2(&env, user, amount, interval_seconds, start_time)
+    }
+
+    /// Creates an AutoSave schedule whose first run is one interval from now
+    pub fn create_autosave_now(
+        env: Env,
+        user: Address,
+        amount: i128,
+        interval_seconds: u64,
+    ) -> Result<u64, SavingsError> {
+        ensure_not_paused(&env)?;
+        autosave::create_autosave_now(&env, user, amount, interval_seconds)
+    }
+
     /// Executes an AutoSave schedule if it's due
     pub fn execute_autosave(env: Env, schedule_id: u64) -> Result<(), SavingsError> {
         ensure_not_paused(&env)?;
         autosave::execute_autosave(&env, schedule_id)
     }
 
+    /// Executes a due AutoSave schedule as a keeper, returning the reward paid
+    pub fn keeper_execute_autosave(
+        env: Env,
+        keeper: Address,
+        schedule_id: u64,
+    ) -> Result<i128, SavingsError> {
+        ensure_not_paused(&env)?;
+        autosave::keeper_execute_autosave(&env, keeper, schedule_id)
+    }
+
+    /// Sets the keeper reward rate in basis points (admin only)
+    pub fn set_keeper_reward_rate(env: Env, bps: u32) -> Result<(), SavingsError> {
+        autosave::set_keeper_reward_rate(&env, bps)
+    }
+
+    /// Gets the keeper reward rate in basis points
+    pub fn get_keeper_reward_rate(env: Env) -> u32 {
+        autosave::get_keeper_reward_rate(&env)
+    }
+
+    /// Adds an address to the AutoSave keeper whitelist (admin only)
+    pub fn add_keeper(env: Env, keeper: Address) -> Result<(), SavingsError> {
+        autosave::add_keeper(&env, keeper)
+    }
+
+    /// Removes an address from the AutoSave keeper whitelist (admin only)
+    pub fn remove_keeper(env: Env, keeper: Address) -> Result<(), SavingsError> {
+        autosave::remove_keeper(&env, keeper)
+    }
+
+    /// Gets the AutoSave keeper whitelist; empty means execution is permissionless
+    pub fn get_keepers(env: Env) -> Vec<Address> {
+        autosave::get_keepers(&env)
+    }
+
+    /// Gets the accumulated rewards credited to a keeper
+    pub fn get_keeper_rewards(env: Env, keeper: Address) -> i128 {
+        autosave::get_keeper_rewards(&env, &keeper)
+    }
+
     /// Batch-executes multiple due AutoSave schedules in a single call.
     /// Returns a Vec<bool> indicating success (true) or skip/failure (false) per schedule.
     pub fn execute_due_autosaves(env: Env, schedule_ids: Vec<u64>) -> Vec<bool> {
@@ -880,16 +1701,81 @@ impl NesteraContract {
         autosave::cancel_autosave(&env, user, schedule_id)
     }
 
+    /// Updates an AutoSave schedule's amount and interval; the next run time is kept
+    pub fn update_autosave(
+        env: Env,
+        user: Address,
+        schedule_id: u64,
+        amount: i128,
+        interval_seconds: u64,
+    ) -> Result<(), SavingsError> {
+        ensure_not_paused(&env)?;
+        autosave::update_autosave(&env, user, schedule_id, amount, interval_seconds)
+    }
+
+    /// Re-activates a cancelled AutoSave schedule under its original ID
+    pub fn reactivate_autosave(
+        env: Env,
+        user: Address,
+        schedule_id: u64,
+    ) -> Result<(), SavingsError> {
+        ensure_not_paused(&env)?;
+        autosave::reactivate_autosave(&env, user, schedule_id)
+    }
+
+    /// Pauses all of the user's AutoSave schedules, returning the IDs paused
+    pub fn pause_all_autosaves(env: Env, user: Address) -> Result<Vec<u64>, SavingsError> {
+        ensure_not_paused(&env)?;
+        Ok(autosave::pause_all_autosaves(&env, user))
+    }
+
+    /// Resumes all of the user's paused AutoSave schedules, skipping runs
+    /// missed while paused; returns the IDs resumed
+    pub fn resume_all_autosaves(env: Env, user: Address) -> Result<Vec<u64>, SavingsError> {
+        ensure_not_paused(&env)?;
+        Ok(autosave::resume_all_autosaves(&env, user))
+    }
+
+    /// Whether the AutoSave schedule is paused by `pause_all_autosaves`
+    pub fn is_autosave_paused(env: Env, schedule_id: u64) -> bool {
+        autosave::is_autosave_paused(&env, schedule_id)
+    }
+
+    /// Cancels several owned AutoSave schedules, returning the IDs cancelled
+    pub fn cancel_autosaves(
+        env: Env,
+        user: Address,
+        schedule_ids: Vec<u64>,
+    ) -> Result<Vec<u64>, SavingsError> {
+        ensure_not_paused(&env)?;
+        Ok(autosave::cancel_autosaves(&env, user, schedule_ids))
+    }
+
+    /// Returns the ID the next `create_autosave` will assign
+    pub fn peek_next_autosave_id(env: Env) -> u64 {
+        autosave::peek_next_autosave_id(&env)
+    }
+
+    /// Next run time of an active schedule (`None` if cancelled or missing)
+    pub fn next_autosave_run(env: Env, schedule_id: u64) -> Option<u64> {
+        autosave::next_autosave_run(&env, schedule_id)
+    }
+
     /// Gets an AutoSave schedule by ID
     pub fn get_autosave(env: Env, schedule_id: u64) -> Option<AutoSave> {
         autosave::get_autosave(&env, schedule_id)
     }
 
-    /// Gets all AutoSave schedule IDs for a user
+    /// Gets the IDs of a user's non-cancelled AutoSave schedules
     pub fn get_user_autosaves(env: Env, user: Address) -> Vec<u64> {
         autosave::get_user_autosaves(&env, &user)
     }
 
+    /// Gets a page of every AutoSave schedule ID, for keepers discovering work
+    pub fn get_all_autosaves(env: Env, start: u32, limit: u32) -> Vec<u64> {
+        autosave::get_all_autosaves(&env, start, limit)
+    }
+
     // ========== Config Functions ==========
 
     /// Initializes the protocol configuration. Can only be called once.
@@ -930,16 +1816,80 @@ impl NesteraContract {
         config::pause_contract(&env, admin)
     }
 
+    /// Asset contract savers deposit, once set through governance
+    pub fn get_token(env: Env) -> Option<Address> {
+        config::get_token(&env)
+    }
+
+    /// Transfers lock interest to savers on-chain in `reward_token` at
+    /// withdrawal (admin only); principal stays ledger-only. `None` stops
+    /// the transfer
+    pub fn set_reward_token(
+        env: Env,
+        admin: Address,
+        reward_token: Option<Address>,
+    ) -> Result<(), SavingsError> {
+        config::set_reward_token(&env, admin, reward_token)
+    }
+
+    pub fn get_reward_token(env: Env) -> Option<Address> {
+        config::get_reward_token(&env)
+    }
+
     /// Unpauses the contract via config module (admin only)
     pub fn unpause_contract(env: Env, admin: Address) -> Result<(), SavingsError> {
         config::unpause_contract(&env, admin)
     }
 
+    /// Permanently stops new plans and deposits; withdrawals stay open and
+    /// locks become withdrawable immediately
+    pub fn emergency_shutdown(env: Env, admin: Address) -> Result<(), SavingsError> {
+        config::emergency_shutdown(&env, admin)
+    }
+
+    pub fn is_shutdown(env: Env) -> bool {
+        config::is_shutdown(&env)
+    }
+
+    /// Sets a symbol added as the first topic of every event, so indexers
+    /// can tell deployments apart (admin only, once)
+    pub fn init_event_namespace(
+        env: Env,
+        admin: Address,
+        namespace: Symbol,
+    ) -> Result<(), SavingsError> {
+        config::init_event_namespace(&env, admin, namespace)
+    }
+
+    pub fn get_event_namespace(env: Env) -> Option<Symbol> {
+        config::get_event_namespace(&env)
+    }
+
+    /// Enables or disables creation of one plan type (admin only)
+    pub fn set_plan_type_enabled(
+        env: Env,
+        admin: Address,
+        kind: PlanKind,
+        enabled: bool,
+    ) -> Result<(), SavingsError> {
+        config::set_plan_type_enabled(&env, admin, kind, enabled)
+    }
+
+    pub fn is_plan_type_enabled(env: Env, kind: PlanKind) -> bool {
+        config::is_plan_type_enabled(&env, kind)
+    }
+
     pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) {
         upgrade::upgrade_contract(&env, admin, new_wasm_hash);
     }
 
-    pub fn version(env: Env) -> u32 {
+    /// Returns the version of the deployed contract code
+    pub fn version(_env: Env) -> u32 {
+        upgrade::CONTRACT_VERSION
+    }
+
+    /// Returns the storage schema version recorded by the last upgrade
+    pub fn storage_version(env: Env) -> u32 {
         upgrade::get_version(&env)
     }
 
@@ -965,11 +1915,44 @@ impl NesteraContract {
         governance::init_voting_config(&env, admin, config)
     }
 
+    /// Cumulative governance participation counters
+    pub fn get_governance_stats(env: Env) -> governance::GovernanceStats {
+        governance::get_governance_stats(&env)
+    }
+
+    /// Flattened type tag and parameters of an action proposal's action
+    pub fn describe_action(env: Env, proposal_id: u64) -> Option<governance::ActionDescription> {
+        governance::describe_action(&env, proposal_id)
+    }
+
+    /// Who executed a proposal and when, once it has been executed
+    pub fn get_proposal_execution(env: Env, proposal_id: u64) -> Option<(Address, u64)> {
+        governance::get_proposal_execution(&env, proposal_id)
+    }
+
+    /// Turnout, tallies, quorum and verdict of a proposal after voting closes
+    pub fn get_proposal_result(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<governance::ProposalResult, SavingsError> {
+        governance::get_proposal_result(&env, proposal_id)
+    }
+
     /// Gets the voting configuration
     pub fn get_voting_config(env: Env) -> Result<governance::VotingConfig, SavingsError> {
         governance::get_voting_config(&env)
     }
 
+    /// Returns the ID the next created proposal will receive
+    pub fn peek_next_proposal_id(env: Env) -> u64 {
+        governance::peek_next_proposal_id(&env)
+    }
+
+    /// Returns governance activation state, config and proposal counters in one call
+    pub fn get_governance_status(env: Env) -> governance::GovernanceStatus {
+        governance::get_governance_status(&env)
+    }
+
     /// Creates a new governance proposal
     pub fn create_proposal(
         env: Env,
@@ -1009,6 +1992,20 @@ impl NesteraContract {
         governance::get_voting_power(&env, &user)
     }
 
+    /// Metric votes are currently weighted by
+    pub fn get_voting_power_source(env: Env) -> governance::VotingPowerSource {
+        governance::get_voting_power_source(&env)
+    }
+
+    /// Sets the voting power metric before governance is activated (admin only)
+    pub fn set_voting_power_source(
+        env: Env,
+        admin: Address,
+        source: governance::VotingPowerSource,
+    ) -> Result<(), SavingsError> {
+        governance::set_voting_power_source(&env, admin, source)
+    }
+
     /// Casts a weighted vote on a proposal
     pub fn vote(
         env: Env,
@@ -1030,8 +2027,46 @@ impl NesteraContract {
     }
 
     /// Executes a queued proposal after timelock period
-    pub fn execute_proposal(env: Env, proposal_id: u64) -> Result<(), SavingsError> {
-        governance::execute_proposal(&env, proposal_id)
+    pub fn execute_proposal(
+        env: Env,
+        proposal_id: u64,
+        executor: Address,
+    ) -> Result<(), SavingsError> {
+        governance::execute_proposal(&env, proposal_id, executor)
+    }
+
+    /// Sets the minimum seconds between proposals from one creator (admin only)
+    pub fn set_proposal_cooldown(
+        env: Env,
+        admin: Address,
+        seconds: u64,
+    ) -> Result<(), SavingsError> {
+        governance::set_proposal_cooldown(&env, admin, seconds)
+    }
+
+    /// Gets the per-creator proposal cooldown in seconds
+    pub fn get_proposal_cooldown(env: Env) -> u64 {
+        governance::get_proposal_cooldown(&env)
+    }
+
+    /// Sets the maximum proposal description length in bytes (admin only)
+    pub fn set_max_description_len(env: Env, admin: Address, len: u32) -> Result<(), SavingsError> {
+        governance::set_max_description_len(&env, admin, len)
+    }
+
+    /// Gets the maximum proposal description length in bytes
+    pub fn get_max_description_len(env: Env) -> u32 {
+        governance::get_max_description_len(&env)
+    }
+
+    /// Sets the smallest voting power a vote must carry to count (admin only)
+    pub fn set_min_vote_weight(env: Env, admin: Address, weight: u128) -> Result<(), SavingsError> {
+        governance::set_min_vote_weight(&env, admin, weight)
+    }
+
+    /// Gets the minimum voting power a vote must carry
+    pub fn get_min_vote_weight(env: Env) -> u128 {
+        governance::get_min_vote_weight(&env)
     }
 
     /// Activates governance (admin only, one-time)
@@ -0,0 +1,549 @@
+//! Internal loans drawn from the interest reserve against locked principal.
+//!
+//! A lock with outstanding debt is encumbered: it cannot be withdrawn until
+//! the loan is repaid in full. Loans are disbursed to and repaid from the
+//! borrower's Flexi balance. Debt accrues simple interest at the borrow rate,
+//! which is netted against the lock's payout when it is withdrawn.
+
+use crate::errors::SavingsError;
+use crate::storage_types::DataKey;
+use crate::{ensure_not_paused, ensure_withdrawals_allowed};
+use crate::{flexi, lock, penalty, rates};
+use soroban_sdk::{contracttype, symbol_short, Address, Env};
+
+/// Loan-to-value ratio used until an admin sets one (50%)
+pub const DEFAULT_LOAN_LTV_BPS: u32 = 5_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LoanKey {
+    /// Maximum debt as a share of the lock's principal, in basis points
+    LtvBps,
+    /// Outstanding debt against a lock
+    Debt(u64),
+    /// Annual borrow interest rate in basis points
+    BorrowRateBps,
+    /// Borrow interest accrued on a lock's loan and not yet settled
+    Interest(u64),
+    /// When borrow interest on a lock's loan was last accrued
+    LastAccrual(u64),
+}
+
+/// Sets the maximum loan-to-value ratio (admin only, at most 10_000 bps)
+pub fn set_loan_ltv(env: &Env, admin: Address, ltv_bps: u32) -> Result<(), SavingsError> {
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(SavingsError::Unauthorized)?;
+    if admin != stored_admin {
+        return Err(SavingsError::Unauthorized);
+    }
+    admin.require_auth();
+
+    if ltv_bps > 10_000 {
+        return Err(SavingsError::InvalidFeeBps);
+    }
+    env.storage().instance().set(&LoanKey::LtvBps, &ltv_bps);
+    Ok(())
+}
+
+pub fn get_loan_ltv(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&LoanKey::LtvBps)
+        .unwrap_or(DEFAULT_LOAN_LTV_BPS)
+}
+
+/// Sets the annual borrow interest rate (admin only, at most 10_000 bps).
+///
+/// Applies to debt from the next accrual on; interest already accrued is kept.
+pub fn set_borrow_rate(env: &Env, admin: Address, rate_bps: u32) -> Result<(), SavingsError> {
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(SavingsError::Unauthorized)?;
+    if admin != stored_admin {
+        return Err(SavingsError::Unauthorized);
+    }
+    admin.require_auth();
+
+    if rate_bps > 10_000 {
+        return Err(SavingsError::InvalidInterestRate);
+    }
+    env.storage()
+        .instance()
+        .set(&LoanKey::BorrowRateBps, &rate_bps);
+    Ok(())
+}
+
+/// Annual borrow interest rate in basis points; 0 (interest-free) until set
+pub fn get_borrow_rate(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&LoanKey::BorrowRateBps)
+        .unwrap_or(0)
+}
+
+/// Borrow interest owed on `lock_id`, including accrual since the last
+/// checkpoint
+pub fn get_loan_interest(env: &Env, lock_id: u64) -> Result<i128, SavingsError> {
+    let accrued: i128 = env
+        .storage()
+        .persistent()
+        .get(&LoanKey::Interest(lock_id))
+        .unwrap_or(0);
+    accrued
+        .checked_add(pending_interest(env, lock_id)?)
+        .ok_or(SavingsError::Overflow)
+}
+
+fn pending_interest(env: &Env, lock_id: u64) -> Result<i128, SavingsError> {
+    let Some(last) = env
+        .storage()
+        .persistent()
+        .get::<LoanKey, u64>(&LoanKey::LastAccrual(lock_id))
+    else {
+        return Ok(0);
+    };
+    rates::calculate_annual_interest(
+        get_loan_debt(env, lock_id),
+        get_borrow_rate(env),
+        env.ledger().timestamp().saturating_sub(last),
+        rates::get_rounding_mode(env),
+    )
+}
+
+/// Moves interest accrued since the last checkpoint into the stored total
+fn accrue_interest(env: &Env, lock_id: u64) -> Result<(), SavingsError> {
+    let interest = get_loan_interest(env, lock_id)?;
+    if interest > 0 {
+        env.storage()
+            .persistent()
+            .set(&LoanKey::Interest(lock_id), &interest);
+    }
+    env.storage()
+        .persistent()
+        .set(&LoanKey::LastAccrual(lock_id), &env.ledger().timestamp());
+    Ok(())
+}
+
+/// Clears the borrow interest owed on a lock being withdrawn, charging at
+/// most `available` from its payout into the interest reserve. Returns the
+/// amount charged.
+pub(crate) fn settle_loan_interest(
+    env: &Env,
+    lock_id: u64,
+    available: i128,
+) -> Result<i128, SavingsError> {
+    let owed = get_loan_interest(env, lock_id)?;
+    env.storage()
+        .persistent()
+        .remove(&LoanKey::Interest(lock_id));
+    env.storage()
+        .persistent()
+        .remove(&LoanKey::LastAccrual(lock_id));
+
+    let charged = owed.min(available.max(0));
+    penalty::refill_interest_reserve(env, charged)?;
+    Ok(charged)
+}
+
+/// Charges at most `available` of the borrow interest owed on a lock that
+/// stays open into the interest reserve, keeping the rest owed. Returns the
+/// amount charged.
+pub(crate) fn collect_loan_interest(
+    env: &Env,
+    lock_id: u64,
+    available: i128,
+) -> Result<i128, SavingsError> {
+    let owed = get_loan_interest(env, lock_id)?;
+    if owed == 0 {
+        return Ok(0);
+    }
+    accrue_interest(env, lock_id)?;
+
+    let charged = owed.min(available.max(0));
+    let remaining = owed - charged;
+    if remaining > 0 {
+        env.storage()
+            .persistent()
+            .set(&LoanKey::Interest(lock_id), &remaining);
+    } else {
+        env.storage()
+            .persistent()
+            .remove(&LoanKey::Interest(lock_id));
+    }
+    penalty::refill_interest_reserve(env, charged)?;
+    Ok(charged)
+}
+
+/// Outstanding debt against `lock_id`
+pub fn get_loan_debt(env: &Env, lock_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&LoanKey::Debt(lock_id))
+        .unwrap_or(0)
+}
+
+/// Whether `lock_id` has outstanding debt and so cannot be withdrawn
+pub fn is_encumbered(env: &Env, lock_id: u64) -> bool {
+    get_loan_debt(env, lock_id) > 0
+}
+
+/// Borrows `amount` from the interest reserve against an active lock.
+///
+/// Total debt on the lock may not exceed the LTV share of its principal.
+/// The loan is credited to the user's Flexi balance. Returns the lock's
+/// outstanding debt.
+pub fn borrow_against_lock(
+    env: &Env,
+    user: Address,
+    lock_id: u64,
+    amount: i128,
+) -> Result<i128, SavingsError> {
+    ensure_not_paused(env)?;
+    // Note: user.require_auth() is already called in lib.rs wrapper function
+
+    if amount <= 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    let lock_save = lock::get_lock_save(env, lock_id).ok_or(SavingsError::LockNotFound)?;
+    if lock_save.owner != user {
+        return Err(SavingsError::Unauthorized);
+    }
+    if lock_save.is_withdrawn {
+        return Err(SavingsError::PlanCompleted);
+    }
+
+    let limit = lock_save
+        .amount
+        .checked_mul(get_loan_ltv(env) as i128)
+        .ok_or(SavingsError::Overflow)?
+        / 10_000;
+    let debt = get_loan_debt(env, lock_id)
+        .checked_add(amount)
+        .ok_or(SavingsError::Overflow)?;
+    if debt > limit {
+        return Err(SavingsError::LoanLimitExceeded);
+    }
+
+    accrue_interest(env, lock_id)?;
+    penalty::draw_interest_reserve(env, amount)?;
+    flexi::credit_flexi_balance(env, &user, amount)?;
+    env.storage()
+        .persistent()
+        .set(&LoanKey::Debt(lock_id), &debt);
+
+    crate::events::publish(env, (symbol_short!("borrow"), user, lock_id), amount);
+    Ok(debt)
+}
+
+/// Repays up to the outstanding debt on `lock_id` from the user's Flexi
+/// balance back into the interest reserve.
+///
+/// Once the debt reaches zero the lock is no longer encumbered. Returns the
+/// remaining debt.
+pub fn repay_loan(
+    env: &Env,
+    user: Address,
+    lock_id: u64,
+    amount: i128,
+) -> Result<i128, SavingsError> {
+    ensure_withdrawals_allowed(env)?;
+    // Note: user.require_auth() is already called in lib.rs wrapper function
+
+    if amount <= 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    let lock_save = lock::get_lock_save(env, lock_id).ok_or(SavingsError::LockNotFound)?;
+    if lock_save.owner != user {
+        return Err(SavingsError::Unauthorized);
+    }
+
+    let debt = get_loan_debt(env, lock_id);
+    if amount > debt {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    accrue_interest(env, lock_id)?;
+    flexi::debit_flexi_balance(env, &user, amount)?;
+    penalty::refill_interest_reserve(env, amount)?;
+
+    let remaining = debt - amount;
+    if remaining == 0 {
+        env.storage().persistent().remove(&LoanKey::Debt(lock_id));
+    } else {
+        env.storage()
+            .persistent()
+            .set(&LoanKey::Debt(lock_id), &remaining);
+    }
+
+    crate::events::publish(env, (symbol_short!("repay"), user, lock_id), amount);
+    Ok(remaining)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{
+        LockStatusFilter, NesteraContract, NesteraContractClient, PenaltySplit, SavingsError,
+    };
+    use soroban_sdk::{
+        symbol_short,
+        testutils::{Address as _, Events, Ledger},
+        Address, BytesN, Env, FromVal, IntoVal, Symbol, Val,
+    };
+
+    const YEAR: u64 = 31_557_600;
+
+    /// Deploys the contract with 10_000 in the interest reserve and a user
+    /// holding a 10_000 one-day lock
+    fn setup() -> (Env, NesteraContractClient<'static>, Address, Address, u64) {
+        let env = Env::default();
+        let contract_id = env.register(NesteraContract, ());
+        let client = NesteraContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let admin_pk = BytesN::from_array(&env, &[1u8; 32]);
+
+        env.mock_all_auths();
+        client.initialize(&admin, &admin_pk);
+
+        // Fund the reserve from a broken goal's penalty
+        let funder = Address::generate(&env);
+        client.initialize_user(&funder);
+        client.set_early_break_fee_bps(&1_000);
+        client.set_penalty_split(
+            &admin,
+            &PenaltySplit {
+                fee_pool_bps: 0,
+                interest_reserve_bps: 10_000,
+                staker_bps: 0,
+            },
+        );
+        let goal_id =
+            client.create_goal_save(&funder, &Symbol::new(&env, "fund"), &200_000, &100_000);
+        client.break_goal_save(&funder, &goal_id);
+        assert_eq!(client.get_interest_reserve(), 10_000);
+
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+        let lock_id = client.create_lock_save(&user, &10_000, &86_400);
+
+        (env, client, admin, user, lock_id)
+    }
+
+    #[test]
+    fn test_borrow_within_ltv_credits_flexi() {
+        let (_env, client, _, user, lock_id) = setup();
+
+        assert_eq!(client.get_loan_ltv(), 5_000);
+        assert_eq!(client.borrow_against_lock(&user, &lock_id, &3_000), 3_000);
+        assert_eq!(client.borrow_against_lock(&user, &lock_id, &2_000), 5_000);
+
+        assert_eq!(client.get_loan_debt(&lock_id), 5_000);
+        assert_eq!(client.get_flexi_balance(&user), 5_000);
+        assert_eq!(client.get_interest_reserve(), 5_000);
+    }
+
+    #[test]
+    fn test_borrow_over_ltv_rejected() {
+        let (_env, client, admin, user, lock_id) = setup();
+
+        assert_eq!(
+            client.try_borrow_against_lock(&user, &lock_id, &5_001),
+            Err(Ok(SavingsError::LoanLimitExceeded))
+        );
+
+        client.set_loan_ltv(&admin, &2_000);
+        client.borrow_against_lock(&user, &lock_id, &1_500);
+        assert_eq!(
+            client.try_borrow_against_lock(&user, &lock_id, &501),
+            Err(Ok(SavingsError::LoanLimitExceeded))
+        );
+        assert_eq!(client.get_loan_debt(&lock_id), 1_500);
+    }
+
+    #[test]
+    fn test_encumbered_lock_withdrawable_after_repayment() {
+        let (env, client, _, user, lock_id) = setup();
+
+        client.borrow_against_lock(&user, &lock_id, &4_000);
+        env.ledger().with_mut(|li| li.timestamp += 86_400 + 1);
+
+        assert_eq!(
+            client.try_withdraw_lock_save(&user, &lock_id),
+            Err(Ok(SavingsError::PlanLocked))
+        );
+
+        assert_eq!(client.repay_loan(&user, &lock_id, &1_000), 3_000);
+        assert_eq!(
+            client.try_withdraw_lock_save(&user, &lock_id),
+            Err(Ok(SavingsError::PlanLocked))
+        );
+        assert_eq!(
+            client.try_repay_loan(&user, &lock_id, &3_001),
+            Err(Ok(SavingsError::InvalidAmount))
+        );
+
+        assert_eq!(client.repay_loan(&user, &lock_id, &3_000), 0);
+        assert_eq!(client.get_interest_reserve(), 10_000);
+        assert!(client.try_withdraw_lock_save(&user, &lock_id).is_ok());
+    }
+
+    #[test]
+    fn test_borrow_interest_netted_against_lock_interest() {
+        let (env, client, admin, user, _) = setup();
+        client.set_borrow_rate(&admin, &1_000); // 10% a year
+        let lock_id = client.create_lock_save(&user, &10_000, &YEAR);
+
+        client.borrow_against_lock(&user, &lock_id, &4_000);
+        env.ledger().with_mut(|li| li.timestamp += YEAR / 2);
+
+        // 4_000 at 10% for half a year
+        assert_eq!(client.get_loan_interest(&lock_id), 200);
+        client.repay_loan(&user, &lock_id, &4_000);
+
+        // Repaid debt stops accruing
+        env.ledger().with_mut(|li| li.timestamp += YEAR / 2 + 1);
+        assert_eq!(client.get_loan_interest(&lock_id), 200);
+
+        // 500 lock interest less 200 borrow interest, paid from the reserve
+        assert_eq!(
+            client.withdraw_lock_save_detailed(&user, &lock_id),
+            (10_000, 300)
+        );
+        assert_eq!(client.get_loan_interest(&lock_id), 0);
+        assert_eq!(client.get_interest_reserve(), 10_000 + 200 - 300);
+    }
+
+    #[test]
+    fn test_borrow_interest_beyond_lock_interest_cuts_principal() {
+        let (env, client, admin, user, _) = setup();
+        client.set_borrow_rate(&admin, &10_000); // 100% a year
+        let lock_id = client.create_lock_save(&user, &10_000, &YEAR);
+
+        client.borrow_against_lock(&user, &lock_id, &2_000);
+        env.ledger().with_mut(|li| li.timestamp += YEAR);
+        client.repay_loan(&user, &lock_id, &2_000);
+
+        // 2_000 borrow interest: 500 from lock interest, 1_500 from principal
+        assert_eq!(client.withdraw_lock_save(&user, &lock_id), 8_500);
+    }
+
+    #[test]
+    fn test_encumbered_lock_cannot_be_split_or_merged() {
+        let (_env, client, _, user, lock_id) = setup();
+        let other_id = client.create_lock_save(&user, &10_000, &86_400);
+
+        client.borrow_against_lock(&user, &lock_id, &1_000);
+        assert_eq!(
+            client.try_split_lock_save(&user, &lock_id, &5_000),
+            Err(Ok(SavingsError::PlanLocked))
+        );
+        assert_eq!(
+            client.try_merge_lock_saves(&user, &other_id, &lock_id),
+            Err(Ok(SavingsError::PlanLocked))
+        );
+
+        client.repay_loan(&user, &lock_id, &1_000);
+        assert!(client
+            .try_merge_lock_saves(&user, &other_id, &lock_id)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_encumbered_matured_lock_is_not_withdrawable() {
+        let (env, client, _, user, lock_id) = setup();
+        client.borrow_against_lock(&user, &lock_id, &1_000);
+
+        env.ledger().with_mut(|li| li.timestamp += 86_400);
+        assert!(client.get_withdrawable_locks(&user).is_empty());
+        assert!(client
+            .get_user_locks_by_status(&user, &LockStatusFilter::Matured)
+            .is_empty());
+        assert_eq!(
+            client.get_user_locks_by_status(&user, &LockStatusFilter::Active),
+            soroban_sdk::vec![&env, lock_id]
+        );
+
+        client.repay_loan(&user, &lock_id, &1_000);
+        assert_eq!(
+            client.get_withdrawable_locks(&user),
+            soroban_sdk::vec![&env, lock_id]
+        );
+    }
+
+    #[test]
+    fn test_claim_interest_only_nets_borrow_interest() {
+        let (env, client, admin, user, _) = setup();
+        client.set_borrow_rate(&admin, &1_000); // 10% a year
+        let lock_id = client.create_lock_save(&user, &10_000, &YEAR);
+
+        client.borrow_against_lock(&user, &lock_id, &4_000);
+        env.ledger().with_mut(|li| li.timestamp += YEAR + 1);
+        assert_eq!(
+            client.try_claim_interest_only(&user, &lock_id),
+            Err(Ok(SavingsError::PlanLocked))
+        );
+
+        // 4_000 at 10% for a year, repaid before claiming
+        client.repay_loan(&user, &lock_id, &4_000);
+        assert_eq!(client.get_loan_interest(&lock_id), 400);
+
+        // 500 lock interest less 400 borrow interest
+        assert_eq!(client.claim_interest_only(&user, &lock_id), 100);
+        assert_eq!(client.get_loan_interest(&lock_id), 0);
+        assert_eq!(client.get_interest_reserve(), 10_000 + 400 - 100);
+    }
+
+    fn low_reserve_warnings(env: &Env) -> std::vec::Vec<i128> {
+        let topic: Val = symbol_short!("lowresv").into_val(env);
+        env.events()
+            .all()
+            .iter()
+            .filter(|(_, topics, _)| topics.len() == 1 && topics.get(0).unwrap().shallow_eq(&topic))
+            .map(|(_, _, data)| i128::from_val(env, &data))
+            .collect()
+    }
+
+    #[test]
+    fn test_low_reserve_warning_fires_once_per_crossing() {
+        let (env, client, admin, user, lock_id) = setup();
+        client.set_reserve_warning_threshold(&admin, &Some(6_000));
+
+        client.borrow_against_lock(&user, &lock_id, &3_000);
+        client.borrow_against_lock(&user, &lock_id, &1_000);
+        assert!(low_reserve_warnings(&env).is_empty());
+
+        // 6_000 -> 5_500 crosses the threshold
+        client.borrow_against_lock(&user, &lock_id, &500);
+        assert_eq!(low_reserve_warnings(&env), std::vec![5_500]);
+
+        // Already below: no repeat warning
+        client.borrow_against_lock(&user, &lock_id, &500);
+        assert_eq!(low_reserve_warnings(&env), std::vec![5_500]);
+
+        // Refilled above the threshold, then drained past it again
+        client.repay_loan(&user, &lock_id, &5_000);
+        client.borrow_against_lock(&user, &lock_id, &4_500);
+        assert_eq!(low_reserve_warnings(&env), std::vec![5_500, 5_500]);
+    }
+
+    #[test]
+    fn test_lock_withdrawal_interest_drawn_from_reserve_warns() {
+        let (env, client, admin, user, _) = setup();
+        let lock_id = client.create_lock_save(&user, &10_000, &YEAR);
+        client.set_reserve_warning_threshold(&admin, &Some(9_600));
+
+        // 500 interest takes the reserve from 10_000 to 9_500
+        env.ledger().with_mut(|li| li.timestamp += YEAR);
+        assert_eq!(client.withdraw_lock_save(&user, &lock_id), 10_500);
+        assert_eq!(client.get_interest_reserve(), 9_500);
+        assert_eq!(low_reserve_warnings(&env), std::vec![9_500]);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::errors::SavingsError;
+use crate::rates::div_round;
+use crate::storage_types::RoundingMode;
+
+/// Fixed-point scale: one token unit is `SCALE` fixed-point units (9 decimals)
+pub const SCALE: i128 = 1_000_000_000;
+
+/// Computes `a * b / denominator` rounded per `mode`.
+///
+/// When `a * b` does not fit in an i128 the product is split as
+/// `(a / d) * b + (a % d) * b / d`, which gives the same result as long as
+/// the final value fits. `denominator` must be positive.
+pub fn mul_div(
+    a: i128,
+    b: i128,
+    denominator: i128,
+    mode: RoundingMode,
+) -> Result<i128, SavingsError> {
+    if denominator <= 0 {
+        return Err(SavingsError::InternalError);
+    }
+
+    if let Some(product) = a.checked_mul(b) {
+        return Ok(div_round(product, denominator, mode));
+    }
+
+    let whole = (a / denominator)
+        .checked_mul(b)
+        .ok_or(SavingsError::Overflow)?;
+    let part = (a % denominator)
+        .checked_mul(b)
+        .ok_or(SavingsError::Overflow)?;
+    whole
+        .checked_add(div_round(part, denominator, mode))
+        .ok_or(SavingsError::Overflow)
+}
+
+/// Converts a whole token amount to fixed point
+pub fn to_fixed(amount: i128) -> Result<i128, SavingsError> {
+    amount.checked_mul(SCALE).ok_or(SavingsError::Overflow)
+}
+
+/// Converts a fixed-point value back to whole token units
+pub fn from_fixed(value: i128, mode: RoundingMode) -> i128 {
+    div_round(value, SCALE, mode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mul_div_matches_direct_product() {
+        for mode in [RoundingMode::TruncateDown, RoundingMode::HalfUp] {
+            assert_eq!(
+                mul_div(1_000, 650, 10_000, mode).unwrap(),
+                div_round(650_000, 10_000, mode)
+            );
+        }
+        assert_eq!(mul_div(7, 3, 2, RoundingMode::TruncateDown).unwrap(), 10);
+        assert_eq!(mul_div(7, 3, 2, RoundingMode::HalfUp).unwrap(), 11);
+    }
+
+    #[test]
+    fn test_mul_div_survives_intermediate_overflow() {
+        let a = i128::MAX / 2;
+        assert!(a.checked_mul(4).is_none());
+        assert_eq!(mul_div(a, 4, 8, RoundingMode::TruncateDown).unwrap(), a / 2);
+        assert_eq!(
+            mul_div(i128::MAX, 2, 1, RoundingMode::TruncateDown),
+            Err(SavingsError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_fixed_round_trip() {
+        let fixed = to_fixed(1_234).unwrap();
+        assert_eq!(fixed, 1_234 * SCALE);
+        assert_eq!(from_fixed(fixed + SCALE / 2, RoundingMode::HalfUp), 1_235);
+        assert_eq!(
+            from_fixed(fixed + SCALE / 2, RoundingMode::TruncateDown),
+            1_234
+        );
+        assert_eq!(to_fixed(i128::MAX), Err(SavingsError::Overflow));
+    }
+}
@@ -0,0 +1,291 @@
+//! Optional M-of-N confirmation for sensitive admin actions.
+//!
+//! Once signers and a threshold are configured, rate changes, pausing and
+//! emergency shutdown can no longer be done by the admin alone. A signer proposes a
+//! [`ProposalAction`], the other signers confirm it by its hash, and the
+//! action runs as soon as the threshold is reached.
+
+use crate::errors::SavingsError;
+use crate::governance::{self, ProposalAction};
+use crate::storage_types::DataKey;
+use soroban_sdk::{contracttype, symbol_short, xdr::ToXdr, Address, BytesN, Env, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MultisigKey {
+    Signers,
+    Threshold,
+    /// Pending action awaiting confirmations, by action hash
+    PendingAction(BytesN<32>),
+    /// Signers that approved a pending action, by action hash
+    AdminApprovals(BytesN<32>),
+}
+
+/// Configures the signer set and threshold (admin only).
+///
+/// A threshold of 0 turns the confirmation layer off again.
+pub fn configure_admin_multisig(
+    env: &Env,
+    admin: Address,
+    signers: Vec<Address>,
+    threshold: u32,
+) -> Result<(), SavingsError> {
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(SavingsError::Unauthorized)?;
+    if admin != stored_admin {
+        return Err(SavingsError::Unauthorized);
+    }
+    admin.require_auth();
+
+    if threshold > signers.len() {
+        return Err(SavingsError::InvalidPlanConfig);
+    }
+
+    env.storage()
+        .instance()
+        .set(&MultisigKey::Signers, &signers);
+    env.storage()
+        .instance()
+        .set(&MultisigKey::Threshold, &threshold);
+    Ok(())
+}
+
+pub fn get_admin_signers(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&MultisigKey::Signers)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Confirmations required for an admin action (0 = confirmation layer off)
+pub fn get_admin_threshold(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&MultisigKey::Threshold)
+        .unwrap_or(0)
+}
+
+pub fn is_enabled(env: &Env) -> bool {
+    get_admin_threshold(env) > 0
+}
+
+/// Rejects single-admin calls to an action covered by the confirmation layer
+pub fn ensure_single_admin_allowed(env: &Env) -> Result<(), SavingsError> {
+    if is_enabled(env) {
+        return Err(SavingsError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Hash identifying an action in the approval storage
+pub fn action_hash(env: &Env, action: &ProposalAction) -> BytesN<32> {
+    env.crypto().sha256(&action.clone().to_xdr(env)).into()
+}
+
+/// Proposes `action`, counting as the proposer's own confirmation.
+///
+/// Returns the action hash the other signers confirm.
+pub fn propose_admin_action(
+    env: &Env,
+    signer: Address,
+    action: ProposalAction,
+) -> Result<BytesN<32>, SavingsError> {
+    require_signer(env, &signer)?;
+
+    let hash = action_hash(env, &action);
+    let pending_key = MultisigKey::PendingAction(hash.clone());
+    if env.storage().persistent().has(&pending_key) {
+        return Err(SavingsError::InvalidPlanConfig);
+    }
+
+    env.storage().persistent().set(&pending_key, &action);
+    env.storage().persistent().set(
+        &MultisigKey::AdminApprovals(hash.clone()),
+        &Vec::from_array(env, [signer]),
+    );
+
+    try_execute(env, &hash)?;
+    Ok(hash)
+}
+
+/// Confirms a pending action. Returns `true` if this confirmation executed it.
+pub fn confirm_admin_action(
+    env: &Env,
+    signer: Address,
+    hash: BytesN<32>,
+) -> Result<bool, SavingsError> {
+    require_signer(env, &signer)?;
+
+    if !env
+        .storage()
+        .persistent()
+        .has(&MultisigKey::PendingAction(hash.clone()))
+    {
+        return Err(SavingsError::PlanNotFound);
+    }
+
+    let approvals_key = MultisigKey::AdminApprovals(hash.clone());
+    let mut approvals = get_admin_approvals(env, hash.clone());
+    if approvals.contains(&signer) {
+        return Err(SavingsError::InvalidPlanConfig);
+    }
+    approvals.push_back(signer);
+    env.storage().persistent().set(&approvals_key, &approvals);
+
+    try_execute(env, &hash)
+}
+
+/// Signers that have approved the pending action with this hash
+pub fn get_admin_approvals(env: &Env, hash: BytesN<32>) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&MultisigKey::AdminApprovals(hash))
+        .unwrap_or(Vec::new(env))
+}
+
+fn require_signer(env: &Env, signer: &Address) -> Result<(), SavingsError> {
+    signer.require_auth();
+
+    if !is_enabled(env) || !get_admin_signers(env).contains(signer) {
+        return Err(SavingsError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Runs the pending action once it has enough approvals
+fn try_execute(env: &Env, hash: &BytesN<32>) -> Result<bool, SavingsError> {
+    let approvals = get_admin_approvals(env, hash.clone());
+    if approvals.len() < get_admin_threshold(env) {
+        return Ok(false);
+    }
+
+    let pending_key = MultisigKey::PendingAction(hash.clone());
+    let action: ProposalAction = env
+        .storage()
+        .persistent()
+        .get(&pending_key)
+        .ok_or(SavingsError::PlanNotFound)?;
+
+    governance::execute_action(env, &action)?;
+
+    env.storage().persistent().remove(&pending_key);
+    env.storage()
+        .persistent()
+        .remove(&MultisigKey::AdminApprovals(hash.clone()));
+    crate::events::publish(env, (symbol_short!("msig_exec"),), hash.clone());
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::governance::ProposalAction;
+    use crate::{NesteraContract, NesteraContractClient, SavingsError};
+    use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env};
+
+    fn setup() -> (Env, NesteraContractClient<'static>, Address, [Address; 3]) {
+        let env = Env::default();
+        let contract_id = env.register(NesteraContract, ());
+        let client = NesteraContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let admin_pk = BytesN::from_array(&env, &[1u8; 32]);
+
+        env.mock_all_auths();
+        client.initialize(&admin, &admin_pk);
+
+        let signers = [
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ];
+        client.configure_admin_multisig(
+            &admin,
+            &vec![
+                &env,
+                signers[0].clone(),
+                signers[1].clone(),
+                signers[2].clone(),
+            ],
+            &2,
+        );
+
+        (env, client, admin, signers)
+    }
+
+    #[test]
+    fn test_two_of_three_executes_on_second_confirmation() {
+        let (_env, client, _admin, signers) = setup();
+
+        let hash = client.propose_admin_action(&signers[0], &ProposalAction::SetFlexiRate(700));
+        assert_eq!(client.get_flexi_rate(), 0);
+        assert_eq!(client.get_admin_approvals(&hash).len(), 1);
+
+        assert!(client.confirm_admin_action(&signers[1], &hash));
+        assert_eq!(client.get_flexi_rate(), 700);
+        assert_eq!(client.get_admin_approvals(&hash).len(), 0);
+
+        // Executed actions are cleared
+        assert_eq!(
+            client.try_confirm_admin_action(&signers[2], &hash),
+            Err(Ok(SavingsError::PlanNotFound))
+        );
+    }
+
+    #[test]
+    fn test_confirmation_rules() {
+        let (env, client, admin, signers) = setup();
+
+        let hash = client.propose_admin_action(&signers[0], &ProposalAction::PauseContract);
+
+        // The proposer cannot confirm twice, outsiders cannot confirm at all
+        assert_eq!(
+            client.try_confirm_admin_action(&signers[0], &hash),
+            Err(Ok(SavingsError::InvalidPlanConfig))
+        );
+        let outsider = Address::generate(&env);
+        assert_eq!(
+            client.try_confirm_admin_action(&outsider, &hash),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+        assert!(!client.is_paused());
+
+        // The admin alone can no longer perform covered actions
+        assert_eq!(
+            client.try_set_flexi_rate(&admin, &700),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_pause(&admin),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_emergency_shutdown(&admin),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+
+        assert!(client.confirm_admin_action(&signers[2], &hash));
+        assert!(client.is_paused());
+    }
+
+    #[test]
+    fn test_emergency_shutdown_needs_confirmation() {
+        let (_env, client, _admin, signers) = setup();
+
+        let hash = client.propose_admin_action(&signers[0], &ProposalAction::EmergencyShutdown);
+        assert!(!client.is_shutdown());
+
+        assert!(client.confirm_admin_action(&signers[1], &hash));
+        assert!(client.is_shutdown());
+    }
+
+    #[test]
+    fn test_threshold_cannot_exceed_signers() {
+        let (env, client, admin, signers) = setup();
+
+        let result =
+            client.try_configure_admin_multisig(&admin, &vec![&env, signers[0].clone()], &2);
+        assert_eq!(result, Err(Ok(SavingsError::InvalidPlanConfig)));
+    }
+}
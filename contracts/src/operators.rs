@@ -0,0 +1,122 @@
+//! Operator approvals.
+//!
+//! A user can approve another address, typically a contract such as a vault,
+//! to manage lock saves on their behalf. The operator then authorizes those
+//! calls itself instead of the user signing each one.
+
+use crate::errors::SavingsError;
+use crate::users;
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OperatorKey {
+    /// (user, operator) -> approved
+    Approval(Address, Address),
+}
+
+/// Approves `operator` to act for `user`
+pub fn approve_operator(env: &Env, user: Address, operator: Address) -> Result<(), SavingsError> {
+    user.require_auth();
+
+    if !users::user_exists(env, &user) {
+        return Err(SavingsError::UserNotFound);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&OperatorKey::Approval(user, operator), &true);
+    Ok(())
+}
+
+/// Withdraws a previously granted operator approval
+pub fn revoke_operator(env: &Env, user: Address, operator: Address) {
+    user.require_auth();
+
+    env.storage()
+        .persistent()
+        .remove(&OperatorKey::Approval(user, operator));
+}
+
+pub fn is_operator(env: &Env, user: &Address, operator: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&OperatorKey::Approval(user.clone(), operator.clone()))
+        .unwrap_or(false)
+}
+
+/// Requires `operator`'s authorization and that `user` has approved it
+pub fn require_operator(env: &Env, user: &Address, operator: &Address) -> Result<(), SavingsError> {
+    operator.require_auth();
+
+    if !is_operator(env, user, operator) {
+        return Err(SavingsError::Unauthorized);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NesteraContract, NesteraContractClient, SavingsError};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        Address, Env,
+    };
+
+    fn setup() -> (Env, NesteraContractClient<'static>, Address) {
+        let env = Env::default();
+        let contract_id = env.register(NesteraContract, ());
+        let client = NesteraContractClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user);
+
+        (env, client, user)
+    }
+
+    #[test]
+    fn test_approved_operator_manages_locks() {
+        let (env, client, user) = setup();
+        let vault = Address::generate(&env);
+
+        assert!(!client.is_operator(&user, &vault));
+        client.approve_operator(&user, &vault);
+        assert!(client.is_operator(&user, &vault));
+
+        let lock_id = client.create_lock_save_for(&vault, &user, &1_000, &100);
+        let lock = client.get_lock_save(&lock_id).unwrap();
+        assert_eq!(lock.owner, user);
+
+        env.ledger().with_mut(|li| li.timestamp += 100);
+        let amount = client.withdraw_lock_save_for(&vault, &user, &lock_id);
+        assert!(amount >= 1_000);
+        assert!(client.get_lock_save(&lock_id).unwrap().is_withdrawn);
+    }
+
+    #[test]
+    fn test_unapproved_operator_rejected() {
+        let (env, client, user) = setup();
+        let stranger = Address::generate(&env);
+
+        assert_eq!(
+            client.try_create_lock_save_for(&stranger, &user, &1_000, &100),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+
+        let lock_id = client.create_lock_save(&user, &1_000, &100);
+        env.ledger().with_mut(|li| li.timestamp += 100);
+        assert_eq!(
+            client.try_withdraw_lock_save_for(&stranger, &user, &lock_id),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+
+        // Revoked approvals stop working
+        client.approve_operator(&user, &stranger);
+        client.revoke_operator(&user, &stranger);
+        assert_eq!(
+            client.try_withdraw_lock_save_for(&stranger, &user, &lock_id),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+    }
+}
@@ -0,0 +1,398 @@
+use crate::errors::SavingsError;
+use crate::events;
+use crate::stats;
+use crate::storage_types::DataKey;
+use soroban_sdk::{contracttype, token, Address, Env};
+
+/// How collected early-withdrawal penalties are divided, in basis points.
+///
+/// The three shares always sum to 10_000. Until an admin configures a split,
+/// the whole penalty goes to the fee pool.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PenaltySplit {
+    /// Share credited to the protocol fee recipient
+    pub fee_pool_bps: u32,
+    /// Share set aside to fund interest payouts
+    pub interest_reserve_bps: u32,
+    /// Share accumulated for distribution to remaining savers
+    pub staker_bps: u32,
+}
+
+impl Default for PenaltySplit {
+    fn default() -> Self {
+        Self {
+            fee_pool_bps: 10_000,
+            interest_reserve_bps: 0,
+            staker_bps: 0,
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PenaltyKey {
+    Split,
+    InterestReserve,
+    StakerPool,
+    /// Reserve balance below which a `lowresv` warning is emitted
+    ReserveWarningThreshold,
+}
+
+/// Sets the penalty split. Admin only; the shares must sum to 10_000.
+pub fn set_penalty_split(
+    env: &Env,
+    admin: Address,
+    split: PenaltySplit,
+) -> Result<(), SavingsError> {
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(SavingsError::Unauthorized)?;
+    if admin != stored_admin {
+        return Err(SavingsError::Unauthorized);
+    }
+    admin.require_auth();
+
+    let total = split
+        .fee_pool_bps
+        .checked_add(split.interest_reserve_bps)
+        .and_then(|t| t.checked_add(split.staker_bps))
+        .ok_or(SavingsError::Overflow)?;
+    if total != 10_000 {
+        return Err(SavingsError::InvalidFeeBps);
+    }
+
+    env.storage().instance().set(&PenaltyKey::Split, &split);
+    Ok(())
+}
+
+pub fn get_penalty_split(env: &Env) -> PenaltySplit {
+    env.storage()
+        .instance()
+        .get(&PenaltyKey::Split)
+        .unwrap_or_default()
+}
+
+/// Total penalties set aside for interest payouts
+pub fn get_interest_reserve(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&PenaltyKey::InterestReserve)
+        .unwrap_or(0)
+}
+
+/// Sets the reserve balance below which draws emit a `lowresv` warning.
+/// Admin only; `None` turns the warning off.
+pub fn set_reserve_warning_threshold(
+    env: &Env,
+    admin: Address,
+    threshold: Option<i128>,
+) -> Result<(), SavingsError> {
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(SavingsError::Unauthorized)?;
+    if admin != stored_admin {
+        return Err(SavingsError::Unauthorized);
+    }
+    admin.require_auth();
+    crate::multisig::ensure_single_admin_allowed(env)?;
+
+    match threshold {
+        Some(threshold) if threshold < 0 => return Err(SavingsError::InvalidAmount),
+        Some(threshold) => env
+            .storage()
+            .instance()
+            .set(&PenaltyKey::ReserveWarningThreshold, &threshold),
+        None => env
+            .storage()
+            .instance()
+            .remove(&PenaltyKey::ReserveWarningThreshold),
+    }
+    Ok(())
+}
+
+pub fn get_reserve_warning_threshold(env: &Env) -> Option<i128> {
+    env.storage()
+        .instance()
+        .get(&PenaltyKey::ReserveWarningThreshold)
+}
+
+/// Total penalties accumulated for remaining savers
+pub fn get_staker_pool(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&PenaltyKey::StakerPool)
+        .unwrap_or(0)
+}
+
+/// Splits a collected penalty per the configured `PenaltySplit`.
+///
+/// The interest reserve and staker shares are credited here. The fee pool
+/// share, which also absorbs rounding dust, is returned so the caller can
+/// route it to the fee recipient the same way as any other fee.
+pub fn distribute_penalty(env: &Env, amount: i128) -> Result<i128, SavingsError> {
+    if amount <= 0 {
+        return Ok(0);
+    }
+
+    let split = get_penalty_split(env);
+    let reserve_share = share_of(amount, split.interest_reserve_bps)?;
+    let staker_share = share_of(amount, split.staker_bps)?;
+
+    credit_pool(env, &PenaltyKey::InterestReserve, reserve_share)?;
+    credit_pool(env, &PenaltyKey::StakerPool, staker_share)?;
+
+    amount
+        .checked_sub(reserve_share)
+        .and_then(|rest| rest.checked_sub(staker_share))
+        .ok_or(SavingsError::Underflow)
+}
+
+fn share_of(amount: i128, bps: u32) -> Result<i128, SavingsError> {
+    Ok(amount
+        .checked_mul(bps as i128)
+        .ok_or(SavingsError::Overflow)?
+        / 10_000)
+}
+
+/// Credits tokens sent straight to the contract to the interest reserve.
+///
+/// Reads the contract's actual balance of the configured token, subtracts
+/// tracked liabilities (TVL plus the reserve and staker pool already on the
+/// books) and credits any surplus to the reserve. Fails with
+/// `MissingParameter` while no token is configured. Returns the amount
+/// credited.
+pub fn sync_reserve(env: &Env, admin: Address) -> Result<i128, SavingsError> {
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(SavingsError::Unauthorized)?;
+    if admin != stored_admin {
+        return Err(SavingsError::Unauthorized);
+    }
+    admin.require_auth();
+
+    let token = crate::config::get_token(env).ok_or(SavingsError::MissingParameter)?;
+    let balance = token::Client::new(env, &token).balance(&env.current_contract_address());
+    let liabilities = stats::total_value_locked(env)?
+        .checked_add(get_interest_reserve(env))
+        .and_then(|t| t.checked_add(get_staker_pool(env)))
+        .ok_or(SavingsError::Overflow)?;
+
+    let surplus = balance.saturating_sub(liabilities).max(0);
+    credit_pool(env, &PenaltyKey::InterestReserve, surplus)?;
+    Ok(surplus)
+}
+
+/// Takes `amount` out of the interest reserve, failing if it holds less.
+///
+/// Emits `lowresv` when this draw takes the reserve from at or above the
+/// warning threshold to below it, so each crossing warns exactly once.
+pub(crate) fn draw_interest_reserve(env: &Env, amount: i128) -> Result<(), SavingsError> {
+    let reserve = get_interest_reserve(env);
+    if reserve < amount {
+        return Err(SavingsError::InsufficientBalance);
+    }
+    let remaining = reserve - amount;
+    env.storage()
+        .persistent()
+        .set(&PenaltyKey::InterestReserve, &remaining);
+
+    if let Some(threshold) = get_reserve_warning_threshold(env) {
+        if reserve >= threshold && remaining < threshold {
+            events::emit_low_reserve(env, remaining);
+        }
+    }
+    Ok(())
+}
+
+/// Funds an interest payout of `amount` from the reserve as far as it goes,
+/// returning the amount drawn.
+///
+/// Unlike a loan, a payout is never refused for a short reserve; the
+/// shortfall is simply not backed by it. The draw warns like any other.
+pub(crate) fn fund_interest_payout(env: &Env, amount: i128) -> Result<i128, SavingsError> {
+    let drawn = amount.min(get_interest_reserve(env));
+    if drawn <= 0 {
+        return Ok(0);
+    }
+    draw_interest_reserve(env, drawn)?;
+    Ok(drawn)
+}
+
+/// Returns `amount` to the interest reserve
+pub(crate) fn refill_interest_reserve(env: &Env, amount: i128) -> Result<(), SavingsError> {
+    credit_pool(env, &PenaltyKey::InterestReserve, amount)
+}
+
+fn credit_pool(env: &Env, key: &PenaltyKey, amount: i128) -> Result<(), SavingsError> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let current: i128 = env.storage().persistent().get(key).unwrap_or(0);
+    let updated = current.checked_add(amount).ok_or(SavingsError::Overflow)?;
+    env.storage().persistent().set(key, &updated);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PenaltySplit;
+    use crate::{NesteraContract, NesteraContractClient, SavingsError};
+    use soroban_sdk::{
+        testutils::Address as _, token::StellarAssetClient, Address, BytesN, Env, Symbol,
+    };
+
+    fn setup() -> (Env, NesteraContractClient<'static>, Address) {
+        let env = Env::default();
+        let contract_id = env.register(NesteraContract, ());
+        let client = NesteraContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let admin_pk = BytesN::from_array(&env, &[1u8; 32]);
+
+        env.mock_all_auths();
+        client.initialize(&admin, &admin_pk);
+
+        (env, client, admin)
+    }
+
+    #[test]
+    fn test_default_split_sends_everything_to_fee_pool() {
+        let (env, client, _) = setup();
+        let user = Address::generate(&env);
+        let treasury = Address::generate(&env);
+
+        client.initialize_user(&user);
+        client.set_fee_recipient(&treasury);
+        client.set_early_break_fee_bps(&1_000);
+
+        assert_eq!(client.get_penalty_split(), PenaltySplit::default());
+
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "car"), &10_000, &2_000);
+        client.break_goal_save(&user, &goal_id);
+
+        assert_eq!(client.get_protocol_fee_balance(&treasury), 200);
+        assert_eq!(client.get_interest_reserve(), 0);
+        assert_eq!(client.get_staker_pool(), 0);
+    }
+
+    #[test]
+    fn test_penalty_distributed_per_configured_split() {
+        let (env, client, admin) = setup();
+        let user = Address::generate(&env);
+        let treasury = Address::generate(&env);
+
+        client.initialize_user(&user);
+        client.set_fee_recipient(&treasury);
+        client.set_early_break_fee_bps(&1_000); // 10%
+        client.set_penalty_split(
+            &admin,
+            &PenaltySplit {
+                fee_pool_bps: 5_000,
+                interest_reserve_bps: 3_000,
+                staker_bps: 2_000,
+            },
+        );
+
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "car"), &10_000, &2_000);
+        let net = client.break_goal_save(&user, &goal_id);
+
+        // 200 penalty: 100 fee pool, 60 reserve, 40 stakers
+        assert_eq!(net, 1_800);
+        assert_eq!(client.get_protocol_fee_balance(&treasury), 100);
+        assert_eq!(client.get_interest_reserve(), 60);
+        assert_eq!(client.get_staker_pool(), 40);
+    }
+
+    #[test]
+    fn test_penalty_split_must_sum_to_full() {
+        let (_env, client, admin) = setup();
+
+        let result = client.try_set_penalty_split(
+            &admin,
+            &PenaltySplit {
+                fee_pool_bps: 5_000,
+                interest_reserve_bps: 3_000,
+                staker_bps: 1_000,
+            },
+        );
+        assert_eq!(result, Err(Ok(SavingsError::InvalidFeeBps)));
+        assert_eq!(client.get_penalty_split(), PenaltySplit::default());
+    }
+
+    #[test]
+    fn test_set_penalty_split_rejects_non_admin() {
+        let (env, client, _) = setup();
+        let outsider = Address::generate(&env);
+
+        let result = client.try_set_penalty_split(
+            &outsider,
+            &PenaltySplit {
+                fee_pool_bps: 0,
+                interest_reserve_bps: 10_000,
+                staker_bps: 0,
+            },
+        );
+        assert_eq!(result, Err(Ok(SavingsError::Unauthorized)));
+        assert_eq!(client.get_penalty_split(), PenaltySplit::default());
+    }
+
+    #[test]
+    fn test_set_reserve_warning_threshold_rejects_non_admin() {
+        let (env, client, admin) = setup();
+        let outsider = Address::generate(&env);
+
+        assert_eq!(
+            client.try_set_reserve_warning_threshold(&outsider, &Some(1_000)),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+        assert_eq!(client.get_reserve_warning_threshold(), None);
+
+        client.set_reserve_warning_threshold(&admin, &Some(1_000));
+        assert_eq!(client.get_reserve_warning_threshold(), Some(1_000));
+    }
+
+    #[test]
+    fn test_sync_reserve_credits_direct_transfers() {
+        let (env, client, admin) = setup();
+        let user = Address::generate(&env);
+
+        let asset = env.register_stellar_asset_contract_v2(admin.clone());
+        let token = asset.address();
+        let minter = StellarAssetClient::new(&env, &token);
+
+        assert_eq!(
+            client.try_sync_reserve(&admin),
+            Err(Ok(SavingsError::MissingParameter))
+        );
+        env.as_contract(&client.address, || {
+            crate::config::set_token(&env, &token).unwrap();
+        });
+
+        // 5_000 of tracked deposits backed by tokens held by the contract
+        client.initialize_user(&user);
+        client.deposit_flexi(&user, &5_000);
+        minter.mint(&client.address, &5_000);
+        assert_eq!(client.sync_reserve(&admin), 0);
+
+        // An operator tops up the reserve with a plain transfer
+        minter.mint(&client.address, &1_200);
+        assert_eq!(client.sync_reserve(&admin), 1_200);
+        assert_eq!(client.get_interest_reserve(), 1_200);
+
+        // Already-credited funds are not counted twice
+        assert_eq!(client.sync_reserve(&admin), 0);
+        assert_eq!(client.get_interest_reserve(), 1_200);
+
+        let stranger = Address::generate(&env);
+        assert_eq!(
+            client.try_sync_reserve(&stranger),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use crate::governance;
-use crate::storage_types::DataKey;
+use crate::math;
+use crate::multisig;
+use crate::storage_types::{DataKey, RoundingMode};
 use crate::SavingsError;
 use soroban_sdk::{Address, Env};
 
@@ -8,8 +10,10 @@ use soroban_sdk::{Address, Env};
 pub fn set_flexi_rate(env: &Env, caller: Address, rate: i128) -> Result<(), SavingsError> {
     caller.require_auth();
     governance::validate_admin_or_governance(env, &caller)?;
+    multisig::ensure_single_admin_allowed(env)?;
 
-    if rate < 0 {
+    // Negative rates charge a custody fee, but never more than 100% a year
+    if rate < -10_000 {
         return Err(SavingsError::InvalidInterestRate);
     }
     env.storage().instance().set(&DataKey::FlexiRate, &rate);
@@ -19,6 +23,7 @@ pub fn set_flexi_rate(env: &Env, caller: Address, rate: i128) -> Result<(), Savi
 pub fn set_goal_rate(env: &Env, caller: Address, rate: i128) -> Result<(), SavingsError> {
     caller.require_auth();
     governance::validate_admin_or_governance(env, &caller)?;
+    multisig::ensure_single_admin_allowed(env)?;
 
     if rate < 0 {
         return Err(SavingsError::InvalidInterestRate);
@@ -30,6 +35,7 @@ pub fn set_goal_rate(env: &Env, caller: Address, rate: i128) -> Result<(), Savin
 pub fn set_group_rate(env: &Env, caller: Address, rate: i128) -> Result<(), SavingsError> {
     caller.require_auth();
     governance::validate_admin_or_governance(env, &caller)?;
+    multisig::ensure_single_admin_allowed(env)?;
 
     if rate < 0 {
         return Err(SavingsError::InvalidInterestRate);
@@ -46,6 +52,7 @@ pub fn set_lock_rate(
 ) -> Result<(), SavingsError> {
     caller.require_auth();
     governance::validate_admin_or_governance(env, &caller)?;
+    multisig::ensure_single_admin_allowed(env)?;
 
     if rate < 0 {
         return Err(SavingsError::InvalidInterestRate);
@@ -56,6 +63,43 @@ pub fn set_lock_rate(
     Ok(())
 }
 
+/// Sets how fractional interest amounts are rounded when converted back to
+/// whole token units.
+pub fn set_rounding_mode(
+    env: &Env,
+    caller: Address,
+    mode: RoundingMode,
+) -> Result<(), SavingsError> {
+    caller.require_auth();
+    governance::validate_admin_or_governance(env, &caller)?;
+    multisig::ensure_single_admin_allowed(env)?;
+
+    env.storage().instance().set(&DataKey::RoundingMode, &mode);
+    Ok(())
+}
+
+/// Sets how often flexi interest is capitalized.
+///
+/// `0` restores continuous pro-rata accrual; any other interval must be at
+/// least `MIN_FLEXI_COMPOUND_INTERVAL` seconds.
+pub fn set_flexi_compound_interval(
+    env: &Env,
+    caller: Address,
+    interval_seconds: u64,
+) -> Result<(), SavingsError> {
+    caller.require_auth();
+    governance::validate_admin_or_governance(env, &caller)?;
+    multisig::ensure_single_admin_allowed(env)?;
+
+    if interval_seconds != 0 && interval_seconds < MIN_FLEXI_COMPOUND_INTERVAL {
+        return Err(SavingsError::InvalidDuration);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::FlexiCompoundInterval, &interval_seconds);
+    Ok(())
+}
+
 // --- Getters ---
 
 pub fn get_flexi_rate(env: &Env) -> i128 {
@@ -86,10 +130,64 @@ pub fn get_lock_rate(env: &Env, duration_days: u64) -> Result<i128, SavingsError
         .ok_or(SavingsError::PlanNotFound)
 }
 
+pub fn get_flexi_compound_interval(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::FlexiCompoundInterval)
+        .unwrap_or(0)
+}
+
+pub fn get_rounding_mode(env: &Env) -> RoundingMode {
+    env.storage()
+        .instance()
+        .get(&DataKey::RoundingMode)
+        .unwrap_or_default()
+}
+
 // --- Interest Calculation Helpers ---
 
+/// Seconds in an average (Julian) year, used to annualize lock interest
+pub const SECONDS_PER_YEAR: u64 = 31_557_600;
+
+/// Divides `numerator` by a positive `denominator` using the given rounding mode
+pub fn div_round(numerator: i128, denominator: i128, mode: RoundingMode) -> i128 {
+    let quotient = numerator / denominator;
+    match mode {
+        RoundingMode::TruncateDown => quotient,
+        RoundingMode::HalfUp => {
+            let remainder = numerator % denominator;
+            if remainder * 2 >= denominator {
+                quotient + 1
+            } else {
+                quotient
+            }
+        }
+    }
+}
+
+/// Simple annualized interest on `amount` at `rate_bps` over `elapsed_seconds`
+pub fn calculate_annual_interest(
+    amount: i128,
+    rate_bps: u32,
+    elapsed_seconds: u64,
+    mode: RoundingMode,
+) -> Result<i128, SavingsError> {
+    if amount <= 0 || rate_bps == 0 || elapsed_seconds == 0 {
+        return Ok(0);
+    }
+
+    let rate_time = (rate_bps as i128)
+        .checked_mul(elapsed_seconds as i128)
+        .ok_or(SavingsError::Overflow)?;
+    let denominator = 10_000i128 * SECONDS_PER_YEAR as i128;
+
+    math::mul_div(amount, rate_time, denominator, mode)
+}
+
+/// Flexi interest on `balance`; negative at a negative (fee) rate, in which
+/// case it never exceeds the balance itself.
 pub fn calculate_flexi_interest(balance: i128, rate: i128, duration_seconds: u64) -> i128 {
-    if balance <= 0 || rate <= 0 {
+    if balance <= 0 || rate == 0 {
         return 0;
     }
     // Simple Interest: (balance * rate * time) / (10000 * 365 * 24 * 60 * 60)
@@ -97,16 +195,72 @@ pub fn calculate_flexi_interest(balance: i128, rate: i128, duration_seconds: u64
     // 10000 is for basis points
     // Time is in seconds, so we divide by seconds in a year (~31536000)
 
-    // Using i128 for precision and to match input types
-    let numerator = balance
-        .checked_mul(rate)
+    let denominator = 10000i128 * 365 * 24 * 60 * 60;
+
+    rate.checked_mul(duration_seconds as i128)
+        .and_then(|rate_time| {
+            math::mul_div(balance, rate_time, denominator, RoundingMode::TruncateDown).ok()
+        })
         .unwrap_or(0)
-        .checked_mul(duration_seconds as i128)
-        .unwrap_or(0);
+        .max(-balance)
+}
 
-    let denominator = 10000i128 * 365 * 24 * 60 * 60;
+/// Shortest flexi compounding interval accepted (one day)
+pub const MIN_FLEXI_COMPOUND_INTERVAL: u64 = 86_400;
+
+/// Scale of the per-interval growth factor, finer than `math::SCALE` so
+/// raising it to many intervals keeps its precision
+const GROWTH_SCALE: i128 = 1_000_000_000_000_000_000;
+
+/// `growth` (scaled by `GROWTH_SCALE`) raised to `periods`, by squaring;
+/// `None` if it overflows
+fn pow_growth(mut growth: i128, mut periods: u64) -> Option<i128> {
+    let mut result = GROWTH_SCALE;
+    while periods > 0 {
+        if periods & 1 == 1 {
+            result =
+                math::mul_div(result, growth, GROWTH_SCALE, RoundingMode::TruncateDown).ok()?;
+        }
+        periods >>= 1;
+        if periods > 0 {
+            growth =
+                math::mul_div(growth, growth, GROWTH_SCALE, RoundingMode::TruncateDown).ok()?;
+        }
+    }
+    Some(result)
+}
+
+/// Flexi interest on `balance` over `duration_seconds` at the configured rate.
+///
+/// With no compounding interval set this is plain pro-rata accrual. Otherwise
+/// interest is capitalized at the end of every full interval and the trailing
+/// partial interval accrues pro-rata on the capitalized balance. The full
+/// intervals are applied at once by raising the per-interval growth factor
+/// to their count, so the cost doesn't grow with the elapsed time. Balances
+/// are kept in fixed point so small ones don't lose their fractional
+/// interest; only the final result is truncated.
+pub fn calculate_flexi_accrued_interest(env: &Env, balance: i128, duration_seconds: u64) -> i128 {
+    let rate = get_flexi_rate(env);
+    let interval = get_flexi_compound_interval(env);
+
+    if interval == 0 {
+        return calculate_flexi_interest(balance, rate, duration_seconds);
+    }
+
+    let Ok(start) = math::to_fixed(balance) else {
+        return calculate_flexi_interest(balance, rate, duration_seconds);
+    };
+    let growth = GROWTH_SCALE + calculate_flexi_interest(GROWTH_SCALE, rate, interval);
+    let Some(mut capitalized) =
+        pow_growth(growth, duration_seconds / interval).and_then(|factor| {
+            math::mul_div(start, factor, GROWTH_SCALE, RoundingMode::TruncateDown).ok()
+        })
+    else {
+        return calculate_flexi_interest(balance, rate, duration_seconds);
+    };
+    capitalized += calculate_flexi_interest(capitalized, rate, duration_seconds % interval);
 
-    numerator / denominator
+    math::from_fixed(capitalized - start, RoundingMode::TruncateDown)
 }
 
 pub fn calculate_lock_interest(amount: i128, rate: i128) -> i128 {
@@ -1,5 +1,9 @@
-use crate::{NesteraContract, NesteraContractClient, SavingsError};
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+use crate::{NesteraContract, NesteraContractClient, RoundingMode, SavingsError};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, BytesN, Env, IntoVal,
+};
 
 fn setup() -> (Env, NesteraContractClient<'static>, Address) {
     let env = Env::default();
@@ -111,7 +115,205 @@ fn test_invalid_rates() {
     let (env, client, admin) = setup();
     env.mock_all_auths();
 
-    // Try set negative rate
-    let res = client.try_set_flexi_rate(&admin, &-100);
+    // Negative rates may not charge more than 100% a year
+    let res = client.try_set_flexi_rate(&admin, &-10_001);
     assert_eq!(res.unwrap_err(), Ok(SavingsError::InvalidInterestRate));
 }
+
+#[test]
+fn test_negative_flexi_rate_charges_custody_fee() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+    let year = 365 * 24 * 60 * 60;
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    client.set_flexi_rate(&admin, &-1_000); // -10% a year
+    client.deposit_flexi(&user, &1_000_000);
+
+    env.ledger().with_mut(|li| li.timestamp += year);
+    assert_eq!(client.accrue_flexi_interest(&user), -100_000);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("flx_fee"), user.clone()).into_val(&env)
+    );
+    let fee: i128 = data.into_val(&env);
+    assert_eq!(fee, 100_000);
+
+    assert_eq!(client.get_flexi_balance(&user), 900_000);
+    assert_eq!(client.get_user(&user).total_balance, 900_000);
+}
+
+#[test]
+fn test_negative_flexi_rate_floors_balance_at_zero() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+    let year = 365 * 24 * 60 * 60;
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    client.set_flexi_rate(&admin, &-10_000);
+    client.deposit_flexi(&user, &1_000);
+
+    env.ledger().with_mut(|li| li.timestamp += 3 * year);
+    assert_eq!(client.accrue_flexi_interest(&user), -1_000);
+    assert_eq!(client.get_flexi_balance(&user), 0);
+    assert_eq!(client.get_user(&user).total_balance, 0);
+
+    // Nothing left to charge
+    env.ledger().with_mut(|li| li.timestamp += year);
+    assert_eq!(client.accrue_flexi_interest(&user), 0);
+}
+
+#[test]
+fn test_rounding_mode_defaults_to_truncate() {
+    let (_env, client, _admin) = setup();
+    assert_eq!(client.get_rounding_mode(), RoundingMode::TruncateDown);
+}
+
+#[test]
+fn test_non_admin_cannot_set_rounding_mode() {
+    let (env, client, _admin) = setup();
+    let stranger = Address::generate(&env);
+
+    env.mock_all_auths();
+    assert_eq!(
+        client.try_set_rounding_mode(&stranger, &RoundingMode::HalfUp),
+        Err(Ok(SavingsError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_lock_payout_under_each_rounding_mode() {
+    let (env, client, admin) = setup();
+    let year = 31_557_600u64;
+
+    env.mock_all_auths();
+    let truncate_user = Address::generate(&env);
+    let half_up_user = Address::generate(&env);
+    client.initialize_user(&truncate_user);
+    client.initialize_user(&half_up_user);
+
+    // 1_010 at 5% for one year accrues 50.5 units of interest
+    let truncate_lock = client.create_lock_save(&truncate_user, &1_010, &year);
+    let half_up_lock = client.create_lock_save(&half_up_user, &1_010, &year);
+
+    env.ledger().with_mut(|li| li.timestamp += year);
+
+    let truncated = client.withdraw_lock_save(&truncate_user, &truncate_lock);
+    assert_eq!(truncated, 1_060);
+
+    client.set_rounding_mode(&admin, &RoundingMode::HalfUp);
+    let rounded = client.withdraw_lock_save(&half_up_user, &half_up_lock);
+    assert_eq!(rounded, 1_061);
+}
+
+#[test]
+fn test_flexi_daily_compounding_beats_continuous_accrual() {
+    let (env, client, admin) = setup();
+    env.mock_all_auths();
+
+    let balance = 1_000_000;
+    let year = 365 * 24 * 60 * 60;
+    assert!(client.try_set_flexi_rate(&admin, &500).is_ok());
+
+    // Continuous pro-rata accrual is plain simple interest
+    assert_eq!(client.get_flexi_compound_interval(), 0);
+    let continuous = client.preview_flexi_interest(&balance, &year);
+    assert_eq!(continuous, 50_000);
+
+    assert!(client
+        .try_set_flexi_compound_interval(&admin, &86_400)
+        .is_ok());
+    let daily = client.preview_flexi_interest(&balance, &year);
+
+    // (1 + 0.05/365)^365 - 1 ≈ 5.127%, truncated once at the end
+    assert!(daily > continuous);
+    assert_eq!(daily, 51_267);
+
+    // Within a single interval there is nothing to capitalize yet
+    assert_eq!(
+        client.preview_flexi_interest(&balance, &43_200),
+        crate::rates::calculate_flexi_interest(balance, 500, 43_200)
+    );
+}
+
+#[test]
+fn test_fixed_point_compounding_keeps_small_balance_interest() {
+    let (env, client, admin) = setup();
+    env.mock_all_auths();
+
+    let balance = 1_000;
+    let year = 365 * 24 * 60 * 60;
+    assert!(client.try_set_flexi_rate(&admin, &500).is_ok());
+    assert!(client
+        .try_set_flexi_compound_interval(&admin, &86_400)
+        .is_ok());
+
+    // Capitalizing whole units each day, 1_000 never earns a single unit
+    let mut integer_balance = balance;
+    for _ in 0..365 {
+        integer_balance += crate::rates::calculate_flexi_interest(integer_balance, 500, 86_400);
+    }
+    assert_eq!(integer_balance - balance, 0);
+
+    // Fixed-point compounding keeps the fractions and pays ~5.1%
+    assert_eq!(client.preview_flexi_interest(&balance, &year), 51);
+}
+
+#[test]
+fn test_compounding_over_many_intervals_uses_closed_form() {
+    let (env, client, admin) = setup();
+    env.mock_all_auths();
+
+    let year = 365 * 24 * 60 * 60;
+    assert!(client.try_set_flexi_rate(&admin, &500).is_ok());
+    assert!(client
+        .try_set_flexi_compound_interval(&admin, &86_400)
+        .is_ok());
+
+    // (1 + 0.05/365)^36_500 - 1 on a century of daily intervals
+    assert_eq!(
+        client.preview_flexi_interest(&1_000_000, &(100 * year)),
+        147_362_346
+    );
+}
+
+#[test]
+fn test_lock_interest_survives_large_intermediate_product() {
+    let amount = i128::MAX / 1_000_000;
+    let year = crate::rates::SECONDS_PER_YEAR;
+
+    // amount * rate * elapsed overflows i128, but the interest itself fits
+    assert!(amount
+        .checked_mul(500)
+        .and_then(|v| v.checked_mul(year as i128))
+        .is_none());
+    assert_eq!(
+        crate::rates::calculate_annual_interest(amount, 500, year, RoundingMode::TruncateDown),
+        Ok(amount / 20)
+    );
+}
+
+#[test]
+fn test_flexi_compound_interval_validation() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+    env.mock_all_auths();
+
+    assert_eq!(
+        client.try_set_flexi_compound_interval(&admin, &3_600),
+        Err(Ok(SavingsError::InvalidDuration))
+    );
+    assert!(client
+        .try_set_flexi_compound_interval(&user, &86_400)
+        .is_err());
+
+    assert!(client
+        .try_set_flexi_compound_interval(&admin, &86_400)
+        .is_ok());
+    assert!(client.try_set_flexi_compound_interval(&admin, &0).is_ok());
+    assert_eq!(client.get_flexi_compound_interval(), 0);
+}
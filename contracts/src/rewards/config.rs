@@ -1,5 +1,6 @@
-use super::storage_types::{RewardsConfig, RewardsDataKey};
+use super::storage_types::{RewardsConfig, RewardsDataKey, TierThresholds};
 use crate::errors::SavingsError;
+use crate::storage_types::DataKey;
 use soroban_sdk::{Address, Env};
 
 /// Initializes the global rewards configuration.
@@ -53,6 +54,43 @@ pub fn get_rewards_config(env: &Env) -> Result<RewardsConfig, SavingsError> {
         .ok_or(SavingsError::InternalError) // Consider adding ConfigNotInitialized to errors.rs
 }
 
+/// Sets the `lifetime_deposited` thresholds for Silver and Gold. Admin only;
+/// thresholds must be positive with Gold above Silver.
+pub fn set_tier_thresholds(
+    env: &Env,
+    admin: Address,
+    thresholds: TierThresholds,
+) -> Result<(), SavingsError> {
+    admin.require_auth();
+
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(SavingsError::Unauthorized)?;
+
+    if admin != stored_admin {
+        return Err(SavingsError::Unauthorized);
+    }
+
+    if thresholds.silver <= 0 || thresholds.gold <= thresholds.silver {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    env.storage()
+        .instance()
+        .set(&RewardsDataKey::TierThresholds, &thresholds);
+    Ok(())
+}
+
+/// Fetches the tier thresholds, falling back to the defaults.
+pub fn get_tier_thresholds(env: &Env) -> TierThresholds {
+    env.storage()
+        .instance()
+        .get(&RewardsDataKey::TierThresholds)
+        .unwrap_or_default()
+}
+
 /// Validates that bonus rates are within 0-100% (0-10000 BPS).
 fn validate_config(config: &RewardsConfig) -> Result<(), SavingsError> {
     if config.streak_bonus_bps > 10_000 || config.long_lock_bonus_bps > 10_000 {
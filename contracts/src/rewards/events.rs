@@ -36,7 +36,8 @@ pub fn emit_points_awarded(env: &Env, user: Address, amount: u128) {
         user: user.clone(),
         amount,
     };
-    env.events().publish(
+    crate::events::publish(
+        env,
         (symbol_short!("rewards"), symbol_short!("awarded"), user),
         event,
     );
@@ -49,7 +50,8 @@ pub fn emit_bonus_awarded(env: &Env, user: Address, amount: u128, bonus_type: Sy
         amount,
         bonus_type,
     };
-    env.events().publish(
+    crate::events::publish(
+        env,
         (symbol_short!("rewards"), symbol_short!("bonus"), user),
         event,
     );
@@ -61,7 +63,8 @@ pub fn emit_points_redeemed(env: &Env, user: Address, amount: u128) {
         user: user.clone(),
         amount,
     };
-    env.events().publish(
+    crate::events::publish(
+        env,
         (symbol_short!("rewards"), symbol_short!("redeem"), user),
         event,
     );
@@ -73,7 +76,8 @@ pub fn emit_streak_updated(env: &Env, user: Address, streak: u32) {
         user: user.clone(),
         streak,
     };
-    env.events().publish(
+    crate::events::publish(
+        env,
         (symbol_short!("rewards"), symbol_short!("streak"), user),
         event,
     );
@@ -6,6 +6,10 @@ use soroban_sdk::{Address, Env, Vec};
 /// Limits iteration to prevent excessive gas usage
 const MAX_RANKING_USERS: u32 = 1000;
 
+/// Maximum number of candidates `get_top_savers` will sort; extra
+/// candidates past this cap are ignored
+pub const MAX_LEADERBOARD_CANDIDATES: u32 = 100;
+
 /// Represents a user's ranking entry
 #[derive(Clone, Debug)]
 pub struct RankingEntry {
@@ -87,6 +91,43 @@ pub fn get_top_users(env: &Env, limit: u32) -> Vec<(Address, u128)> {
     result
 }
 
+/// Ranks a caller-supplied set of addresses by `lifetime_deposited`
+///
+/// Only the first `MAX_LEADERBOARD_CANDIDATES` candidates are considered.
+/// Duplicates and addresses that have never deposited are skipped; ties keep
+/// candidate order.
+///
+/// # Returns
+/// Up to `limit` (Address, lifetime_deposited) tuples sorted descending
+pub fn get_top_savers(env: &Env, candidates: Vec<Address>, limit: u32) -> Vec<(Address, i128)> {
+    let mut savers: Vec<(Address, i128)> = Vec::new(env);
+
+    for i in 0..candidates.len().min(MAX_LEADERBOARD_CANDIDATES) {
+        let candidate = candidates.get(i).unwrap();
+        if savers.iter().any(|(user, _)| user == candidate) {
+            continue;
+        }
+        let deposited = get_user_rewards(env, candidate.clone()).lifetime_deposited;
+        if deposited <= 0 {
+            continue;
+        }
+
+        // Insert after every saver with at least as much, keeping ties stable
+        let mut pos = savers.len();
+        while pos > 0 && savers.get(pos - 1).unwrap().1 < deposited {
+            pos -= 1;
+        }
+        savers.insert(pos, (candidate, deposited));
+    }
+
+    let mut result = Vec::new(env);
+    for i in 0..limit.min(savers.len()) {
+        result.push_back(savers.get(i).unwrap());
+    }
+
+    result
+}
+
 /// Gets the rank of a specific user
 ///
 /// # Arguments
@@ -3,10 +3,22 @@
 use crate::errors::SavingsError;
 use crate::rewards::events::emit_points_redeemed;
 use crate::rewards::storage::{get_user_rewards, save_user_rewards};
+use crate::rewards::storage_types::RewardsDataKey;
 use soroban_sdk::{Address, Env};
 
+/// Points needed for each basis point of early-withdrawal penalty discount
+pub const POINTS_PER_DISCOUNT_BPS: u128 = 100;
+
+/// A pending discount can waive the whole penalty but no more
+pub const MAX_PENALTY_DISCOUNT_BPS: u32 = 10_000;
+
 /// Redeem points for protocol benefits (fee discounts, boost multiplier, etc.)
 ///
+/// Every `POINTS_PER_DISCOUNT_BPS` points redeemed add one basis point to the
+/// user's pending penalty discount, which is used up by their next
+/// early-withdrawal penalty. `amount` must be a whole multiple of
+/// `POINTS_PER_DISCOUNT_BPS`, so no points are spent without buying discount.
+///
 /// # Arguments
 /// * `env` - Contract environment
 /// * `user` - User address redeeming points
@@ -18,11 +30,13 @@ use soroban_sdk::{Address, Env};
 ///
 /// # Safety
 /// * Validates user has sufficient points
+/// * Rejects amounts that are not a multiple of `POINTS_PER_DISCOUNT_BPS`
+/// * Rejects redemptions that would push the discount past a full waiver
 /// * Uses checked arithmetic to prevent underflow
 /// * Emits PointsRedeemed event on success
 pub fn redeem_points(env: &Env, user: Address, amount: u128) -> Result<(), SavingsError> {
     // Validate amount
-    if amount == 0 {
+    if amount == 0 || amount % POINTS_PER_DISCOUNT_BPS != 0 {
         return Err(SavingsError::InvalidAmount);
     }
 
@@ -34,6 +48,14 @@ pub fn redeem_points(env: &Env, user: Address, amount: u128) -> Result<(), Savin
         return Err(SavingsError::InsufficientBalance);
     }
 
+    // Convert to a pending penalty discount
+    let discount = (get_penalty_discount(env, &user) as u128)
+        .checked_add(amount / POINTS_PER_DISCOUNT_BPS)
+        .ok_or(SavingsError::Overflow)?;
+    if discount > MAX_PENALTY_DISCOUNT_BPS as u128 {
+        return Err(SavingsError::InvalidAmount);
+    }
+
     // Deduct points safely
     rewards.total_points = rewards
         .total_points
@@ -42,9 +64,49 @@ pub fn redeem_points(env: &Env, user: Address, amount: u128) -> Result<(), Savin
 
     // Save updated state
     save_user_rewards(env, user.clone(), &rewards);
+    if discount > 0 {
+        env.storage().persistent().set(
+            &RewardsDataKey::PenaltyDiscount(user.clone()),
+            &(discount as u32),
+        );
+    }
 
     // Emit redemption event
     emit_points_redeemed(env, user, amount);
 
     Ok(())
 }
+
+/// Pending early-withdrawal penalty discount for `user`, in basis points
+pub fn get_penalty_discount(env: &Env, user: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&RewardsDataKey::PenaltyDiscount(user.clone()))
+        .unwrap_or(0)
+}
+
+/// Applies and clears the user's pending discount, returning the reduced
+/// penalty. A zero penalty leaves the discount for a later withdrawal.
+pub(crate) fn apply_penalty_discount(
+    env: &Env,
+    user: &Address,
+    penalty: i128,
+) -> Result<i128, SavingsError> {
+    if penalty <= 0 {
+        return Ok(penalty);
+    }
+
+    let discount_bps = get_penalty_discount(env, user);
+    if discount_bps == 0 {
+        return Ok(penalty);
+    }
+    env.storage()
+        .persistent()
+        .remove(&RewardsDataKey::PenaltyDiscount(user.clone()));
+
+    let discount = penalty
+        .checked_mul(discount_bps as i128)
+        .ok_or(SavingsError::Overflow)?
+        / 10_000;
+    penalty.checked_sub(discount).ok_or(SavingsError::Underflow)
+}
@@ -1,6 +1,6 @@
-use super::storage_types::{RewardsDataKey, UserRewards};
+use super::storage_types::{RewardTier, RewardsDataKey, UserRewards};
 use crate::errors::SavingsError;
-use crate::rewards::config::get_rewards_config;
+use crate::rewards::config::{get_rewards_config, get_tier_thresholds};
 use crate::rewards::events::{emit_bonus_awarded, emit_points_awarded, emit_streak_updated};
 use soroban_sdk::{Address, Env, Symbol};
 
@@ -35,6 +35,20 @@ pub fn get_user_rewards(env: &Env, user: Address) -> UserRewards {
     }
 }
 
+/// Tier the user has reached through lifetime deposit volume
+pub fn get_reward_tier(env: &Env, user: Address) -> RewardTier {
+    let deposited = get_user_rewards(env, user).lifetime_deposited;
+    let thresholds = get_tier_thresholds(env);
+
+    if deposited >= thresholds.gold {
+        RewardTier::Gold
+    } else if deposited >= thresholds.silver {
+        RewardTier::Silver
+    } else {
+        RewardTier::Bronze
+    }
+}
+
 /// Force-saves the user rewards state
 pub fn save_user_rewards(env: &Env, user: Address, rewards: &UserRewards) {
     let key = RewardsDataKey::UserLedger(user);
@@ -77,6 +91,16 @@ pub fn add_points(env: &Env, user: Address, points: u128) -> Result<(), SavingsE
     Ok(())
 }
 
+/// Takes back a deposit's `amount` of lifetime volume and the `points` it
+/// earned, floored at zero
+pub fn revoke_deposit_award(env: &Env, user: &Address, amount: i128, points: u128) {
+    let mut rewards = get_user_rewards(env, user.clone());
+    rewards.lifetime_deposited = rewards.lifetime_deposited.saturating_sub(amount).max(0);
+    rewards.total_points = rewards.total_points.saturating_sub(points);
+    rewards.daily_points_earned = rewards.daily_points_earned.saturating_sub(points);
+    save_user_rewards(env, user.clone(), &rewards);
+}
+
 /// Resets the streak back to zero
 pub fn reset_streak(env: &Env, user: Address) {
     let mut rewards = get_user_rewards(env, user.clone());
@@ -118,11 +142,26 @@ pub fn update_streak(env: &Env, user: Address) -> Result<u32, SavingsError> {
     Ok(rewards.current_streak)
 }
 
+/// Adds `amount` to the user's deposit volume. Counted for every deposit,
+/// even when the anti-farming rules below withhold points, since governance
+/// weighs votes by it.
+fn record_lifetime_deposit(env: &Env, user: &Address, amount: i128) -> Result<(), SavingsError> {
+    let mut user_rewards = get_user_rewards(env, user.clone());
+    user_rewards.lifetime_deposited = user_rewards
+        .lifetime_deposited
+        .checked_add(amount)
+        .ok_or(SavingsError::Overflow)?;
+    save_user_rewards(env, user.clone(), &user_rewards);
+    Ok(())
+}
+
 pub fn award_deposit_points(env: &Env, user: Address, amount: i128) -> Result<(), SavingsError> {
     if amount <= 0 {
         return Ok(());
     }
 
+    record_lifetime_deposit(env, &user, amount)?;
+
     // 1. Fetch Config & Check if Enabled
     let config = match get_rewards_config(env) {
         Ok(config) if config.enabled => config,
@@ -203,11 +242,6 @@ pub fn award_deposit_points(env: &Env, user: Address, amount: i128) -> Result<()
         .checked_add(capped_points)
         .ok_or(SavingsError::Overflow)?;
 
-    user_rewards.lifetime_deposited = user_rewards
-        .lifetime_deposited
-        .checked_add(amount)
-        .ok_or(SavingsError::Overflow)?;
-
     // 6. Save and Emit Event
     save_user_rewards(env, user.clone(), &user_rewards);
 
@@ -281,11 +315,11 @@ pub fn award_goal_completion_bonus(env: &Env, user: Address) -> Result<u128, Sav
 #[cfg(test)]
 mod tests {
     use super::STREAK_WINDOW_SECS;
-    use crate::rewards::storage_types::RewardsConfig;
-    use crate::{NesteraContract, NesteraContractClient, PlanType};
+    use crate::rewards::storage_types::{RewardTier, RewardsConfig, TierThresholds};
+    use crate::{NesteraContract, NesteraContractClient, PlanType, SavingsError};
     use soroban_sdk::{
         testutils::{Address as _, Ledger},
-        Address, BytesN, Env,
+        Address, BytesN, Env, Symbol,
     };
 
     fn setup_env_with_rewards(
@@ -444,4 +478,95 @@ mod tests {
         env.ledger().with_mut(|li| li.timestamp += 24 * 60 * 60);
         assert_eq!(client.update_streak(&user), 2);
     }
+
+    #[test]
+    fn test_lifetime_deposited_rises_on_every_deposit_path() {
+        let (env, client, _) = setup_env_with_rewards(default_rewards_config());
+        let user = Address::generate(&env);
+        env.mock_all_auths();
+        client.initialize_user(&user);
+
+        client.deposit_flexi(&user, &1_000);
+        assert_eq!(client.get_user_rewards(&user).lifetime_deposited, 1_000);
+
+        client.create_lock_save(&user, &2_000, &(30 * 24 * 60 * 60));
+        assert_eq!(client.get_user_rewards(&user).lifetime_deposited, 3_000);
+
+        let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "trip"), &10_000, &500);
+        client.deposit_to_goal_save(&user, &goal_id, &250);
+        assert_eq!(client.get_user_rewards(&user).lifetime_deposited, 3_750);
+        assert_eq!(client.get_voting_power(&user), 3_750);
+    }
+
+    #[test]
+    fn test_lifetime_deposited_counts_deposits_without_points() {
+        let mut config = default_rewards_config();
+        config.min_deposit_for_rewards = 1_000;
+        let (env, client, _) = setup_env_with_rewards(config);
+        let user = Address::generate(&env);
+        env.mock_all_auths();
+        client.initialize_user(&user);
+
+        client.deposit_flexi(&user, &400);
+
+        let rewards = client.get_user_rewards(&user);
+        assert_eq!(rewards.total_points, 0);
+        assert_eq!(rewards.lifetime_deposited, 400);
+    }
+
+    #[test]
+    fn test_reward_tier_follows_lifetime_deposits() {
+        let (env, client, admin) = setup_env_with_rewards(default_rewards_config());
+        let user = Address::generate(&env);
+        env.mock_all_auths();
+        client.initialize_user(&user);
+
+        let thresholds = TierThresholds {
+            silver: 1_000,
+            gold: 5_000,
+        };
+        client.set_tier_thresholds(&admin, &thresholds);
+        assert_eq!(client.get_tier_thresholds(), thresholds);
+
+        assert_eq!(client.get_reward_tier(&user), RewardTier::Bronze);
+
+        client.deposit_flexi(&user, &999);
+        assert_eq!(client.get_reward_tier(&user), RewardTier::Bronze);
+
+        client.deposit_flexi(&user, &1);
+        assert_eq!(client.get_reward_tier(&user), RewardTier::Silver);
+
+        client.deposit_flexi(&user, &3_999);
+        assert_eq!(client.get_reward_tier(&user), RewardTier::Silver);
+
+        client.deposit_flexi(&user, &1);
+        assert_eq!(client.get_reward_tier(&user), RewardTier::Gold);
+    }
+
+    #[test]
+    fn test_tier_thresholds_validated_and_admin_only() {
+        let (env, client, admin) = setup_env_with_rewards(default_rewards_config());
+        env.mock_all_auths();
+
+        assert_eq!(client.get_tier_thresholds(), TierThresholds::default());
+
+        let inverted = TierThresholds {
+            silver: 5_000,
+            gold: 5_000,
+        };
+        assert_eq!(
+            client.try_set_tier_thresholds(&admin, &inverted),
+            Err(Ok(SavingsError::InvalidAmount))
+        );
+
+        let valid = TierThresholds {
+            silver: 1_000,
+            gold: 5_000,
+        };
+        let stranger = Address::generate(&env);
+        assert_eq!(
+            client.try_set_tier_thresholds(&stranger, &valid),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+    }
 }
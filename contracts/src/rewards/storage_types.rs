@@ -29,9 +29,37 @@ pub struct UserRewards {
     pub last_reward_day: u64,      // Last day rewards were earned (ledger day)
 }
 
+/// Standing earned through lifetime deposit volume
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum RewardTier {
+    Bronze,
+    Silver,
+    Gold,
+}
+
+/// Minimum `lifetime_deposited` for each tier above Bronze
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierThresholds {
+    pub silver: i128,
+    pub gold: i128,
+}
+
+impl Default for TierThresholds {
+    fn default() -> Self {
+        Self {
+            silver: 10_000,
+            gold: 100_000,
+        }
+    }
+}
+
 #[contracttype]
 pub enum RewardsDataKey {
     Config,
     UserLedger(Address),
-    AllUsers, // Tracks all users with rewards for ranking
+    AllUsers,                 // Tracks all users with rewards for ranking
+    PenaltyDiscount(Address), // Pending early-withdrawal discount (bps) bought with points
+    TierThresholds,
 }
@@ -0,0 +1,391 @@
+use crate::errors::SavingsError;
+use crate::events;
+use crate::storage_types::{DataKey, PlanStat, PlanType, PlanTypeStats, Solvency};
+use crate::{penalty, ttl};
+use soroban_sdk::{contracttype, token, Address, Env};
+
+/// Selects which per-plan-type bucket a stats update applies to.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PlanKind {
+    Flexi,
+    Lock,
+    Goal,
+    Group,
+}
+
+impl From<&PlanType> for PlanKind {
+    fn from(plan_type: &PlanType) -> Self {
+        match plan_type {
+            PlanType::Flexi => PlanKind::Flexi,
+            PlanType::Lock(_) => PlanKind::Lock,
+            PlanType::Goal(_, _, _) => PlanKind::Goal,
+            PlanType::Group(_, _, _, _) => PlanKind::Group,
+        }
+    }
+}
+
+/// Aggregate counters kept alongside `DataKey::PlanTypeStats`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StatsKey {
+    /// Interest the open locks will have earned by maturity
+    LockInterestCommitted,
+}
+
+/// Returns the aggregate per-plan-type statistics.
+///
+/// The counters are maintained incrementally on create/deposit/withdraw, so
+/// this is a single storage read.
+pub fn get_plan_type_stats(env: &Env) -> PlanTypeStats {
+    let key = DataKey::PlanTypeStats;
+    let stats = env.storage().persistent().get(&key).unwrap_or_default();
+
+    // Extend TTL on read (only if the key exists)
+    ttl::extend_config_ttl(env, &key);
+
+    stats
+}
+
+/// Total principal held across every plan type (the protocol's TVL).
+pub fn total_value_locked(env: &Env) -> Result<i128, SavingsError> {
+    let stats = get_plan_type_stats(env);
+    stats
+        .flexi
+        .total_principal
+        .checked_add(stats.lock.total_principal)
+        .and_then(|t| t.checked_add(stats.goal.total_principal))
+        .and_then(|t| t.checked_add(stats.group.total_principal))
+        .ok_or(SavingsError::Overflow)
+}
+
+/// Compares the contract's balance of the configured token with its
+/// liabilities.
+///
+/// Built from the aggregate counters only, so it costs the same however many
+/// plans are open. Fails with `MissingParameter` while no token is configured.
+pub fn get_solvency(env: &Env) -> Result<Solvency, SavingsError> {
+    let token = crate::config::get_token(env).ok_or(SavingsError::MissingParameter)?;
+    let token_balance = token::Client::new(env, &token).balance(&env.current_contract_address());
+
+    Ok(Solvency {
+        token_balance,
+        principal: total_value_locked(env)?,
+        projected_interest: get_lock_interest_committed(env),
+        reserve: penalty::get_interest_reserve(env),
+    })
+}
+
+/// Interest the open locks will have earned by maturity
+pub fn get_lock_interest_committed(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StatsKey::LockInterestCommitted)
+        .unwrap_or(0)
+}
+
+/// Adjusts the committed lock interest by `delta` as locks open, change
+/// or close.
+pub fn record_lock_interest_committed(env: &Env, delta: i128) -> Result<(), SavingsError> {
+    if delta == 0 {
+        return Ok(());
+    }
+    let key = StatsKey::LockInterestCommitted;
+    // Floored at zero for the same reason as `record_plan_closed`
+    let committed = get_lock_interest_committed(env)
+        .checked_add(delta)
+        .ok_or(SavingsError::Overflow)?
+        .max(0);
+    env.storage().persistent().set(&key, &committed);
+    ttl::extend_config_ttl(env, &key);
+    Ok(())
+}
+
+/// Records a newly opened plan and its initial principal.
+pub fn record_plan_opened(env: &Env, kind: PlanKind, principal: i128) -> Result<(), SavingsError> {
+    update_stat(env, kind, |stat| {
+        stat.count = stat.count.checked_add(1).ok_or(SavingsError::Overflow)?;
+        stat.total_principal = stat
+            .total_principal
+            .checked_add(principal)
+            .ok_or(SavingsError::Overflow)?;
+        Ok(())
+    })
+}
+
+/// Records a closed (withdrawn/broken) plan and the principal that left with it.
+pub fn record_plan_closed(env: &Env, kind: PlanKind, principal: i128) -> Result<(), SavingsError> {
+    update_stat(env, kind, |stat| {
+        // Saturating so that plans created before tracking began can still close
+        stat.count = stat.count.saturating_sub(1);
+        stat.total_principal = stat.total_principal.saturating_sub(principal).max(0);
+        Ok(())
+    })
+}
+
+/// Records principal added to an already open plan.
+pub fn record_principal_added(env: &Env, kind: PlanKind, amount: i128) -> Result<(), SavingsError> {
+    update_stat(env, kind, |stat| {
+        stat.total_principal = stat
+            .total_principal
+            .checked_add(amount)
+            .ok_or(SavingsError::Overflow)?;
+        Ok(())
+    })
+}
+
+/// Records principal removed from a plan that stays open.
+pub fn record_principal_removed(
+    env: &Env,
+    kind: PlanKind,
+    amount: i128,
+) -> Result<(), SavingsError> {
+    update_stat(env, kind, |stat| {
+        stat.total_principal = stat.total_principal.saturating_sub(amount).max(0);
+        Ok(())
+    })
+}
+
+/// Returns the most recently issued deposit receipt number (0 before any deposit).
+pub fn get_deposit_seq(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DepositSeq)
+        .unwrap_or(0)
+}
+
+/// Assigns the next deposit receipt number and emits the receipt event.
+///
+/// All plan types draw from the same counter, so receipt numbers are unique
+/// and gapless across the whole contract, independent of plan IDs.
+pub fn issue_deposit_receipt(env: &Env, user: &Address, amount: i128) -> Result<u64, SavingsError> {
+    let key = DataKey::DepositSeq;
+    let seq = get_deposit_seq(env)
+        .checked_add(1)
+        .ok_or(SavingsError::Overflow)?;
+    env.storage().persistent().set(&key, &seq);
+    ttl::extend_counter_ttl(env, &key);
+
+    events::emit_deposit_receipt(env, user.clone(), seq, amount);
+
+    Ok(seq)
+}
+
+// ========== Helper Functions ==========
+
+fn update_stat<F>(env: &Env, kind: PlanKind, f: F) -> Result<(), SavingsError>
+where
+    F: FnOnce(&mut PlanStat) -> Result<(), SavingsError>,
+{
+    let key = DataKey::PlanTypeStats;
+    let mut stats: PlanTypeStats = env.storage().persistent().get(&key).unwrap_or_default();
+
+    let stat = match kind {
+        PlanKind::Flexi => &mut stats.flexi,
+        PlanKind::Lock => &mut stats.lock,
+        PlanKind::Goal => &mut stats.goal,
+        PlanKind::Group => &mut stats.group,
+    };
+    f(stat)?;
+
+    env.storage().persistent().set(&key, &stats);
+    ttl::extend_config_ttl(env, &key);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NesteraContract, NesteraContractClient, SavingsError};
+    use soroban_sdk::{
+        symbol_short,
+        testutils::{Address as _, Events, Ledger},
+        token::{StellarAssetClient, TokenClient},
+        Address, BytesN, Env, FromVal, IntoVal, String, Symbol, Val,
+    };
+
+    fn setup() -> (Env, NesteraContractClient<'static>) {
+        let env = Env::default();
+        let contract_id = env.register(NesteraContract, ());
+        let client = NesteraContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let admin_pk = BytesN::from_array(&env, &[1u8; 32]);
+
+        env.mock_all_auths();
+        client.initialize(&admin, &admin_pk);
+
+        (env, client)
+    }
+
+    /// Returns `(user, seq, amount)` of the most recent deposit receipt event
+    fn last_receipt(env: &Env) -> (Address, u64, i128) {
+        let receipt: Val = symbol_short!("receipt").into_val(env);
+        let (_, topics, data) = env
+            .events()
+            .all()
+            .iter()
+            .filter(|(_, topics, _)| {
+                topics
+                    .get(0)
+                    .map(|t| t.shallow_eq(&receipt))
+                    .unwrap_or(false)
+            })
+            .last()
+            .expect("no deposit receipt emitted");
+        (
+            Address::from_val(env, &topics.get(1).unwrap()),
+            u64::from_val(env, &topics.get(2).unwrap()),
+            i128::from_val(env, &data),
+        )
+    }
+
+    #[test]
+    fn test_solvency_tracks_deposits_and_withdrawals() {
+        let (env, client) = setup();
+        let user = Address::generate(&env);
+        let issuer = Address::generate(&env);
+        let year = 31_557_600u64;
+
+        let token = env.register_stellar_asset_contract_v2(issuer).address();
+        let minter = StellarAssetClient::new(&env, &token);
+        assert_eq!(
+            client.try_get_solvency(),
+            Err(Ok(SavingsError::MissingParameter))
+        );
+        env.as_contract(&client.address, || {
+            crate::config::set_token(&env, &token).unwrap();
+        });
+
+        client.initialize_user(&user);
+        client.deposit_flexi(&user, &5_000);
+        client.create_lock_save(&user, &1_000_000, &year);
+        minter.mint(&client.address, &1_005_000);
+
+        let solvency = client.get_solvency();
+        assert_eq!(solvency.token_balance, 1_005_000);
+        assert_eq!(solvency.principal, 1_005_000);
+        // 5% over the year the lock runs
+        assert_eq!(solvency.projected_interest, 50_000);
+        assert_eq!(solvency.reserve, 0);
+
+        env.ledger().with_mut(|li| li.timestamp += year / 2);
+        client.withdraw_flexi(&user, &2_000);
+        TokenClient::new(&env, &token).transfer(&client.address, &user, &2_000);
+
+        let solvency = client.get_solvency();
+        assert_eq!(solvency.token_balance, 1_003_000);
+        assert_eq!(solvency.principal, 1_003_000);
+        assert_eq!(solvency.projected_interest, 50_000);
+        assert_eq!(
+            solvency.principal,
+            client.get_plan_type_stats().flexi.total_principal
+                + client.get_plan_type_stats().lock.total_principal
+        );
+    }
+
+    #[test]
+    fn test_plan_type_stats_default_empty() {
+        let (_env, client) = setup();
+
+        let stats = client.get_plan_type_stats();
+        assert_eq!(stats.flexi.count, 0);
+        assert_eq!(stats.lock.total_principal, 0);
+        assert_eq!(stats.goal.count, 0);
+        assert_eq!(stats.group.count, 0);
+    }
+
+    #[test]
+    fn test_plan_type_stats_track_mixed_plans() {
+        let (env, client) = setup();
+        let user1 = Address::generate(&env);
+        let user2 = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user1);
+        client.initialize_user(&user2);
+
+        // Flexi: two depositors, repeat deposit does not bump the count
+        client.deposit_flexi(&user1, &1_000);
+        client.deposit_flexi(&user1, &500);
+        client.deposit_flexi(&user2, &2_000);
+
+        // Lock: two locks
+        let lock_id = client.create_lock_save(&user1, &3_000, &100);
+        client.create_lock_save(&user2, &4_000, &100);
+
+        // Goal: one goal with a follow-up deposit
+        let goal_id = client.create_goal_save(&user1, &Symbol::new(&env, "car"), &10_000, &1_000);
+        client.deposit_to_goal_save(&user1, &goal_id, &500);
+
+        // Group: one group with a contribution
+        let group_id = client.create_group_save(
+            &user2,
+            &String::from_str(&env, "Trip"),
+            &String::from_str(&env, "Team trip"),
+            &String::from_str(&env, "travel"),
+            &5_000,
+            &0,
+            &100,
+            &true,
+            &0,
+            &1_000,
+        );
+        client.contribute_to_group_save(&user2, &group_id, &700);
+
+        let stats = client.get_plan_type_stats();
+        assert_eq!(stats.flexi.count, 2);
+        assert_eq!(stats.flexi.total_principal, 3_500);
+        assert_eq!(stats.lock.count, 2);
+        assert_eq!(stats.lock.total_principal, 7_000);
+        assert_eq!(stats.goal.count, 1);
+        assert_eq!(stats.goal.total_principal, 1_500);
+        assert_eq!(stats.group.count, 1);
+        assert_eq!(stats.group.total_principal, 700);
+
+        // Withdrawals
+        client.withdraw_flexi(&user2, &2_000);
+        client.withdraw_flexi(&user1, &500);
+        env.ledger().with_mut(|li| li.timestamp += 101);
+        client.withdraw_lock_save(&user1, &lock_id);
+        client.break_goal_save(&user1, &goal_id);
+
+        let stats = client.get_plan_type_stats();
+        assert_eq!(stats.flexi.count, 1);
+        assert_eq!(stats.flexi.total_principal, 1_000);
+        assert_eq!(stats.lock.count, 1);
+        assert_eq!(stats.lock.total_principal, 4_000);
+        assert_eq!(stats.goal.count, 0);
+        assert_eq!(stats.goal.total_principal, 0);
+        assert_eq!(stats.group.count, 1);
+        assert_eq!(stats.group.total_principal, 700);
+    }
+
+    #[test]
+    fn test_deposit_receipts_are_sequential_across_plan_types() {
+        let (env, client) = setup();
+        let user1 = Address::generate(&env);
+        let user2 = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize_user(&user1);
+        client.initialize_user(&user2);
+        assert_eq!(client.get_deposit_seq(), 0);
+
+        client.deposit_flexi(&user1, &1_000);
+        assert_eq!(last_receipt(&env), (user1.clone(), 1, 1_000));
+
+        client.create_lock_save(&user2, &4_000, &100);
+        assert_eq!(last_receipt(&env), (user2.clone(), 2, 4_000));
+
+        client.deposit_flexi(&user2, &250);
+        assert_eq!(last_receipt(&env), (user2.clone(), 3, 250));
+
+        client.create_lock_save(&user1, &3_000, &100);
+        assert_eq!(last_receipt(&env), (user1.clone(), 4, 3_000));
+
+        client.deposit_flexi(&user1, &500);
+        assert_eq!(last_receipt(&env), (user1, 5, 500));
+
+        assert_eq!(client.get_deposit_seq(), 5);
+    }
+}
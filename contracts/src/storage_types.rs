@@ -10,6 +10,17 @@ pub enum PlanType {
     Group(u64, bool, u32, i128),
 }
 
+/// How fractional interest is rounded to whole token units
+#[contracttype]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum RoundingMode {
+    /// Drop any fractional unit (protocol-favoring, the historical behavior)
+    #[default]
+    TruncateDown,
+    /// Round to the nearest unit, with exact halves rounded up
+    HalfUp,
+}
+
 /// Represents an individual savings plan for a user
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -30,7 +41,17 @@ pub struct SavingsPlan {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct User {
     pub total_balance: i128,
+    /// Plans the user has ever opened: savings plans, locks and goals.
+    ///
+    /// A lifetime count that withdrawals never decrement, since it also
+    /// allocates `SavingsPlan` IDs and bounds the plan scans in `views`.
     pub savings_count: u32,
+    /// Cumulative interest paid out to the user across all withdrawals
+    pub lifetime_interest: i128,
+    /// Timestamp of the user's first deposit into any plan (0 = none yet)
+    pub first_deposit: u64,
+    /// Timestamp of the user's latest deposit or withdrawal (0 = none yet)
+    pub last_activity: u64,
 }
 
 /// Represents a Lock Save plan with fixed duration
@@ -45,10 +66,32 @@ impl User {
         Self {
             total_balance: 0,
             savings_count: 0,
+            lifetime_interest: 0,
+            first_deposit: 0,
+            last_activity: 0,
+        }
+    }
+
+    /// Stamps `now` as the latest activity, and as `first_deposit` if this is
+    /// the user's first deposit
+    pub fn record_activity(&mut self, now: u64, is_deposit: bool) {
+        if is_deposit && self.first_deposit == 0 {
+            self.first_deposit = now;
         }
+        self.last_activity = now;
     }
 }
 
+/// Notification preferences a user wants every front-end to honor
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct UserPrefs {
+    /// Days before a lock matures to remind the user (0 = no reminder)
+    pub maturity_reminder_days: u32,
+    /// Whether to notify the user when an AutoSave runs or fails
+    pub autosave_alerts: bool,
+}
+
 /// Represents a group savings plan
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -69,6 +112,50 @@ pub struct GroupSave {
     pub is_completed: bool,
 }
 
+/// What `settle_matured` does with a lock's proceeds
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum MatureAction {
+    /// Pay principal and interest out to the owner
+    #[default]
+    Withdraw,
+    /// Deposit principal and interest into the owner's goal with this ID
+    IntoGoal(u64),
+    /// Re-lock principal and interest for the same term
+    Rollover,
+}
+
+/// Which of a user's locks `get_user_locks_by_status` returns
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LockStatusFilter {
+    /// Not withdrawn and not yet withdrawable: scheduled, accruing, or
+    /// encumbered by a loan
+    Active,
+    /// Matured (or released by shutdown) and ready to withdraw
+    Matured,
+    /// Already withdrawn
+    Withdrawn,
+    /// Every lock the user holds
+    All,
+}
+
+/// Where a lock is in its lifecycle, as reported by `get_lock_state`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LockState {
+    /// Created with a future `start_time`; not accruing yet
+    Scheduled,
+    /// Accruing interest, not yet matured
+    Active,
+    /// Matured (or released by shutdown) and ready to withdraw
+    Matured,
+    /// Has an outstanding loan against it and can't be withdrawn until repaid
+    Encumbered,
+    /// Already withdrawn
+    Withdrawn,
+}
+
 /// Represents a Lock Save plan with fixed duration and maturity
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -99,6 +186,28 @@ pub enum SavingsError {
     StrategyNotFound = 9,
 }
 
+/// Per-lock outcome of a batch lock withdrawal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockWithdrawResult {
+    pub lock_id: u64,
+    /// Amount paid out; 0 when the withdrawal failed
+    pub amount: i128,
+    /// `SavingsError` code when the withdrawal failed, `None` on success
+    pub error: Option<u32>,
+}
+
+/// A user's interest accrued so far, broken down by the plan types that pay it
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct InterestBreakdown {
+    /// Flexi interest not yet applied to the balance (negative at a fee rate)
+    pub flexi: i128,
+    /// Interest accrued on open locks
+    pub lock: i128,
+    pub total: i128,
+}
+
 /// Represents a Goal Save plan with target amount
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -126,6 +235,16 @@ pub struct AutoSave {
     pub is_active: bool,
 }
 
+/// What `create_autosave_v2` returns about the schedule it created
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoSaveReceipt {
+    pub schedule_id: u64,
+    /// When the schedule will first run
+    pub first_execution_time: u64,
+    pub interval_seconds: u64,
+}
+
 /// Storage keys for the contract's persistent data
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -196,6 +315,62 @@ pub enum DataKey {
     LockRate(u64),
     /// Maps (plan_type, plan_id) to disabled status
     DisabledStrategy(PlanType, u64),
+    /// Aggregate plan counts and principal per plan type
+    PlanTypeStats,
+    /// Minimum principal accepted when creating a lock save
+    MinLockAmount,
+    /// Maximum principal accepted when creating a lock save
+    MaxLockAmount,
+    /// Rounding applied to interest calculations
+    RoundingMode,
+    /// Global list of every AutoSave schedule ID, in creation order
+    AllAutoSaveIds,
+    /// Reward (basis points of the scheduled amount) paid to AutoSave keepers
+    KeeperRewardRate,
+    /// Addresses allowed to execute AutoSave schedules (empty = permissionless)
+    KeeperWhitelist,
+    /// Seconds between flexi interest capitalizations (0 = continuous accrual)
+    FlexiCompoundInterval,
+    /// Highest rate (basis points) an admin may grant on a custom-rate lock
+    MaxCustomLockRate,
+    /// Last deposit receipt number issued, shared by every plan type
+    DepositSeq,
+    /// Maps user to their front-end notification preferences
+    UserPrefs(Address),
+}
+
+/// Open plan count and outstanding principal for a single plan type
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlanStat {
+    pub count: u32,
+    pub total_principal: i128,
+}
+
+/// The contract's token holdings next to what it owes savers
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Solvency {
+    /// Tokens actually held by the contract
+    pub token_balance: i128,
+    /// Outstanding principal across every plan type (TVL)
+    pub principal: i128,
+    /// Interest the open locks will have earned by maturity
+    pub projected_interest: i128,
+    /// Interest reserve counter
+    pub reserve: i128,
+}
+
+/// Protocol-wide aggregate statistics broken down by plan type.
+///
+/// For Flexi, `count` is the number of users holding a non-zero balance.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlanTypeStats {
+    pub flexi: PlanStat,
+    pub lock: PlanStat,
+    pub goal: PlanStat,
+    pub group: PlanStat,
 }
 
 /// Payload structure that the admin signs off-chain
@@ -91,7 +91,8 @@ pub fn register_strategy(
         .persistent()
         .extend_ttl(&list_key, ttl::LOW_THRESHOLD, ttl::EXTEND_TO);
 
-    env.events().publish(
+    crate::events::publish(
+        env,
         (symbol_short!("strat"), symbol_short!("register")),
         strategy_address,
     );
@@ -133,7 +134,8 @@ pub fn disable_strategy(
         .persistent()
         .extend_ttl(&info_key, ttl::LOW_THRESHOLD, ttl::EXTEND_TO);
 
-    env.events().publish(
+    crate::events::publish(
+        env,
         (symbol_short!("strat"), symbol_short!("disable")),
         strategy_address,
     );
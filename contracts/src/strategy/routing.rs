@@ -106,7 +106,8 @@ pub fn route_to_strategy(
         .extend_ttl(&position_key, ttl::LOW_THRESHOLD, ttl::EXTEND_TO);
 
     // Emit event
-    env.events().publish(
+    crate::events::publish(
+        env,
         (symbol_short!("strat"), symbol_short!("deposit")),
         (strategy_address, amount, shares),
     );
@@ -179,7 +180,8 @@ pub fn withdraw_from_strategy(
     // Call strategy withdraw
     let returned = client.strategy_withdraw(&to, &withdraw_amount);
 
-    env.events().publish(
+    crate::events::publish(
+        env,
         (symbol_short!("strat"), symbol_short!("withdraw")),
         (position.strategy, withdraw_amount, returned),
     );
@@ -264,7 +266,8 @@ pub fn harvest_strategy(env: &Env, strategy_address: Address) -> Result<i128, Sa
             .extend_ttl(&yield_key, ttl::LOW_THRESHOLD, ttl::EXTEND_TO);
     }
 
-    env.events().publish(
+    crate::events::publish(
+        env,
         (symbol_short!("strat"), symbol_short!("harvest")),
         (strategy_address, actual_yield, treasury_fee, user_yield),
     );
@@ -1,7 +1,10 @@
 #![cfg(test)]
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env, IntoVal, String, Symbol,
+};
 
-use crate::{NesteraContract, NesteraContractClient, SavingsError};
+use crate::{InterestBreakdown, NesteraContract, NesteraContractClient, SavingsError, UserPrefs};
 
 fn setup() -> (Env, NesteraContractClient<'static>, Address) {
     let env = Env::default();
@@ -302,3 +305,288 @@ fn test_error_user_not_found() {
     let result = client.try_break_group_save(&non_existent_user, &group_id);
     assert_eq!(result.unwrap_err(), Ok(SavingsError::UserNotFound));
 }
+
+#[test]
+fn test_version_matches_constant() {
+    let (_env, client, _admin) = setup();
+
+    assert_eq!(client.version(), crate::upgrade::CONTRACT_VERSION);
+    // No upgrade has run yet, so no schema version is recorded
+    assert_eq!(client.storage_version(), 0);
+}
+
+#[test]
+fn test_user_prefs_default_set_and_update() {
+    let (env, client, _admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+
+    assert_eq!(client.get_user_prefs(&user), UserPrefs::default());
+
+    let prefs = UserPrefs {
+        maturity_reminder_days: 7,
+        autosave_alerts: true,
+    };
+    client.set_user_prefs(&user, &prefs);
+    assert_eq!(client.get_user_prefs(&user), prefs);
+
+    let updated = UserPrefs {
+        maturity_reminder_days: 1,
+        autosave_alerts: false,
+    };
+    client.set_user_prefs(&user, &updated);
+    assert_eq!(client.get_user_prefs(&user), updated);
+}
+
+#[test]
+fn test_user_prefs_require_initialized_user() {
+    let (env, client, _admin) = setup();
+    let stranger = Address::generate(&env);
+
+    env.mock_all_auths();
+    let result = client.try_set_user_prefs(&stranger, &UserPrefs::default());
+    assert_eq!(result.unwrap_err(), Ok(SavingsError::UserNotFound));
+    assert_eq!(client.get_user_prefs(&stranger), UserPrefs::default());
+}
+
+#[test]
+fn test_interest_breakdown_by_plan_type() {
+    let (env, client, admin) = setup();
+    let user = Address::generate(&env);
+    let year = 31_557_600u64;
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    client.set_flexi_rate(&admin, &500);
+
+    client.create_lock_save(&user, &100_000_000, &year);
+    client.deposit_flexi(&user, &1_000_000);
+    client.create_goal_save(&user, &Symbol::new(&env, "house"), &10_000_000, &4_000_000);
+
+    assert_eq!(
+        client.get_interest_breakdown(&user),
+        InterestBreakdown::default()
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += year / 2);
+    let breakdown = client.get_interest_breakdown(&user);
+
+    assert_eq!(breakdown.lock, 2_500_000);
+    // Flexi accrues over a 365-day year
+    assert_eq!(breakdown.flexi, 25_017);
+    // The open goal earns nothing
+    assert_eq!(breakdown.total, breakdown.lock + breakdown.flexi);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_get_interest_breakdown(&stranger),
+        Err(Ok(SavingsError::UserNotFound))
+    );
+}
+
+#[test]
+fn test_user_activity_timestamps() {
+    let (env, client, _admin) = setup();
+    let user = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&user);
+    let fresh = client.get_user(&user);
+    assert_eq!(fresh.first_deposit, 0);
+    assert_eq!(fresh.last_activity, 0);
+
+    // Flexi deposit sets both timestamps
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.deposit_flexi(&user, &500);
+    let after_flexi = client.get_user(&user);
+    assert_eq!(after_flexi.first_deposit, 1_000);
+    assert_eq!(after_flexi.last_activity, 1_000);
+
+    // Lock deposit only moves last_activity
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    let lock_id = client.create_lock_save(&user, &1_000, &100);
+    let after_lock = client.get_user(&user);
+    assert_eq!(after_lock.first_deposit, 1_000);
+    assert_eq!(after_lock.last_activity, 2_000);
+
+    // Goal deposit
+    env.ledger().with_mut(|li| li.timestamp = 3_000);
+    let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "bike"), &10_000, &100);
+    env.ledger().with_mut(|li| li.timestamp = 3_500);
+    client.deposit_to_goal_save(&user, &goal_id, &100);
+    assert_eq!(client.get_user(&user).last_activity, 3_500);
+
+    // Withdrawals count as activity too
+    env.ledger().with_mut(|li| li.timestamp = 4_000);
+    client.withdraw_lock_save(&user, &lock_id);
+    assert_eq!(client.get_user(&user).last_activity, 4_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    client.withdraw_flexi(&user, &100);
+    let after_withdraw = client.get_user(&user);
+    assert_eq!(after_withdraw.last_activity, 5_000);
+    assert_eq!(after_withdraw.first_deposit, 1_000);
+}
+
+#[test]
+fn test_verify_invariants_detects_balance_drift() {
+    let (env, client, _admin) = setup();
+    let user = Address::generate(&env);
+
+    client.initialize_user(&user);
+    client.deposit_flexi(&user, &2_000);
+    let lock_id = client.create_lock_save(&user, &5_000, &86_400);
+    client.create_lock_save(&user, &3_000, &(2 * 86_400));
+    assert!(client.verify_invariants(&user));
+
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+    client.withdraw_lock_save(&user, &lock_id);
+    assert!(client.verify_invariants(&user));
+
+    // Corrupt the stored aggregate directly
+    env.as_contract(&client.address, || {
+        let key = crate::DataKey::User(user.clone());
+        let mut user_data: crate::User = env.storage().persistent().get(&key).unwrap();
+        user_data.total_balance += 1;
+        env.storage().persistent().set(&key, &user_data);
+    });
+    assert!(!client.verify_invariants(&user));
+
+    assert_eq!(
+        client.try_verify_invariants(&Address::generate(&env)),
+        Err(Ok(SavingsError::UserNotFound))
+    );
+}
+
+#[test]
+fn test_verify_invariants_holds_across_goal_vesting_and_group() {
+    let (env, client, _admin) = setup();
+    let user = Address::generate(&env);
+
+    client.initialize_user(&user);
+    client.deposit_flexi(&user, &2_000);
+    let goal_id = client.create_goal_save(&user, &Symbol::new(&env, "trip"), &1_000, &1_000);
+    let vesting_id = client.create_vesting_lock(&user, &4_000, &0, &(4 * 86_400));
+    let group_id = client.create_group_save(
+        &user,
+        &String::from_str(&env, "Club"),
+        &String::from_str(&env, "Shared pot"),
+        &String::from_str(&env, "savings"),
+        &10_000,
+        &0,
+        &100,
+        &true,
+        &1,
+        &1_000,
+    );
+    client.contribute_to_group_save(&user, &group_id, &500);
+    assert!(client.verify_invariants(&user));
+
+    // The completed goal's payout is credited to the balance
+    client.withdraw_completed_goal_save(&user, &goal_id);
+    assert!(client.verify_invariants(&user));
+
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+    assert_eq!(client.claim_vested(&user, &vesting_id), 1_000);
+    client.break_group_save(&user, &group_id);
+    assert!(client.verify_invariants(&user));
+    assert_eq!(client.get_user(&user).total_balance, 2_000 + 1_000 + 3_000);
+}
+
+#[test]
+fn test_legacy_user_record_migrates_with_zero_timestamps() {
+    let (env, client, _admin) = setup();
+    let user = Address::generate(&env);
+
+    env.as_contract(&client.address, || {
+        let legacy = crate::users::LegacyUser {
+            total_balance: 700,
+            savings_count: 2,
+            lifetime_interest: 30,
+        };
+        env.storage()
+            .persistent()
+            .set(&crate::DataKey::User(user.clone()), &legacy);
+    });
+
+    let migrated = client.get_user(&user);
+    assert_eq!(migrated.total_balance, 700);
+    assert_eq!(migrated.savings_count, 2);
+    assert_eq!(migrated.lifetime_interest, 30);
+    assert_eq!(migrated.first_deposit, 0);
+    assert_eq!(migrated.last_activity, 0);
+
+    // The next activity rewrites the record in the new layout
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 9_000);
+    client.deposit_flexi(&user, &100);
+    let upgraded = client.get_user(&user);
+    assert_eq!(upgraded.total_balance, 800);
+    assert_eq!(upgraded.first_deposit, 9_000);
+}
+
+#[test]
+fn test_baseline_user_record_still_loads() {
+    let (env, client, _admin) = setup();
+    let user = Address::generate(&env);
+
+    // A record in the originally deployed two-field layout, written raw
+    env.as_contract(&client.address, || {
+        let mut raw: soroban_sdk::Map<Symbol, soroban_sdk::Val> = soroban_sdk::Map::new(&env);
+        raw.set(Symbol::new(&env, "total_balance"), 1_200i128.into_val(&env));
+        raw.set(Symbol::new(&env, "savings_count"), 3u32.into_val(&env));
+        env.storage()
+            .persistent()
+            .set(&crate::DataKey::User(user.clone()), &raw);
+    });
+
+    let migrated = client.get_user(&user);
+    assert_eq!(migrated.total_balance, 1_200);
+    assert_eq!(migrated.savings_count, 3);
+    assert_eq!(migrated.lifetime_interest, 0);
+    assert_eq!(migrated.first_deposit, 0);
+    assert_eq!(migrated.last_activity, 0);
+
+    // The next activity rewrites the record in the current layout
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 9_000);
+    client.deposit_flexi(&user, &100);
+    let upgraded = client.get_user(&user);
+    assert_eq!(upgraded.total_balance, 1_300);
+    assert_eq!(upgraded.first_deposit, 9_000);
+    assert_eq!(upgraded.last_activity, 9_000);
+}
+
+#[test]
+fn test_dormant_user_detection() {
+    let (env, client, _admin) = setup();
+    let active = Address::generate(&env);
+    let idle = Address::generate(&env);
+    let unknown = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.initialize_user(&active);
+    client.initialize_user(&idle);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.deposit_flexi(&idle, &100);
+    env.ledger().with_mut(|li| li.timestamp = 50_000);
+    client.deposit_flexi(&active, &100);
+
+    env.ledger().with_mut(|li| li.timestamp = 60_000);
+    let threshold = 30_000;
+    assert!(!client.is_dormant(&active, &threshold));
+    assert!(client.is_dormant(&idle, &threshold));
+    assert!(!client.is_dormant(&unknown, &threshold));
+
+    // Exactly at the threshold is not yet dormant
+    assert!(!client.is_dormant(&active, &10_000));
+
+    let users = soroban_sdk::vec![&env, active.clone(), idle.clone(), unknown];
+    assert_eq!(
+        client.list_dormant_users(&users, &threshold),
+        soroban_sdk::vec![&env, idle]
+    );
+}
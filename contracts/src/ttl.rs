@@ -29,11 +29,12 @@ pub fn extend_instance_ttl(env: &Env) {
 }
 
 /// Extends TTL for user-related storage entries
-/// Includes: User data, FlexiBalance, TotalBalance
+/// Includes: User data, FlexiBalance, TotalBalance, UserPrefs
 pub fn extend_user_ttl(env: &Env, user: &Address) {
     let user_key = DataKey::User(user.clone());
     let flexi_key = DataKey::FlexiBalance(user.clone());
     let total_key = DataKey::TotalBalance(user.clone());
+    let prefs_key = DataKey::UserPrefs(user.clone());
 
     // Only extend TTL if the key exists
     if env.storage().persistent().has(&user_key) {
@@ -53,6 +54,8 @@ pub fn extend_user_ttl(env: &Env, user: &Address) {
             .persistent()
             .extend_ttl(&total_key, LOW_THRESHOLD, EXTEND_TO);
     }
+
+    crate::upgrade::extend_versioned_ttl(env, &prefs_key, LOW_THRESHOLD, EXTEND_TO);
 }
 
 /// Extends TTL for a savings plan
@@ -1,9 +1,10 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{contracttype, Address, Env, TryFromVal, Val, Vec};
 
 use crate::ensure_not_paused;
 use crate::errors::SavingsError;
-use crate::storage_types::{DataKey, User};
+use crate::storage_types::{DataKey, User, UserPrefs};
 use crate::ttl;
+use crate::upgrade;
 
 /// Check if a user exists in storage
 ///
@@ -22,6 +23,74 @@ pub fn user_exists(env: &Env, user: &Address) -> bool {
     exists
 }
 
+/// `User` as stored once lifetime interest was tracked but before the
+/// activity timestamps were added
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyUser {
+    pub total_balance: i128,
+    pub savings_count: u32,
+    pub lifetime_interest: i128,
+}
+
+impl From<LegacyUser> for User {
+    fn from(legacy: LegacyUser) -> Self {
+        User {
+            total_balance: legacy.total_balance,
+            savings_count: legacy.savings_count,
+            lifetime_interest: legacy.lifetime_interest,
+            first_deposit: 0,
+            last_activity: 0,
+        }
+    }
+}
+
+/// `User` as originally deployed, before lifetime interest was tracked
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BaselineUser {
+    pub total_balance: i128,
+    pub savings_count: u32,
+}
+
+impl From<BaselineUser> for User {
+    fn from(baseline: BaselineUser) -> Self {
+        User {
+            total_balance: baseline.total_balance,
+            savings_count: baseline.savings_count,
+            lifetime_interest: 0,
+            first_deposit: 0,
+            last_activity: 0,
+        }
+    }
+}
+
+/// Reads a user record, upgrading one written in an older layout with the
+/// missing fields zeroed
+pub(crate) fn load_user(env: &Env, user: &Address) -> Option<User> {
+    let raw: Val = env
+        .storage()
+        .persistent()
+        .get(&DataKey::User(user.clone()))?;
+    if let Ok(user_data) = User::try_from_val(env, &raw) {
+        return Some(user_data);
+    }
+    if let Ok(legacy) = LegacyUser::try_from_val(env, &raw) {
+        return Some(legacy.into());
+    }
+    BaselineUser::try_from_val(env, &raw).ok().map(User::from)
+}
+
+/// Records a deposit or withdrawal by `user` at the current time
+pub(crate) fn record_activity(env: &Env, user: &Address, is_deposit: bool) {
+    if let Some(mut user_data) = load_user(env, user) {
+        user_data.record_activity(env.ledger().timestamp(), is_deposit);
+        env.storage()
+            .persistent()
+            .set(&DataKey::User(user.clone()), &user_data);
+    }
+}
+
 /// Get a user from storage
 ///
 /// # Arguments
@@ -31,12 +100,7 @@ pub fn user_exists(env: &Env, user: &Address) -> bool {
 /// # Returns
 /// `Ok(User)` if found, `Err(SavingsError::UserNotFound)` otherwise
 pub fn get_user(env: &Env, user: &Address) -> Result<User, SavingsError> {
-    let key = DataKey::User(user.clone());
-    let user_data = env
-        .storage()
-        .persistent()
-        .get(&key)
-        .ok_or(SavingsError::UserNotFound)?;
+    let user_data = load_user(env, user).ok_or(SavingsError::UserNotFound)?;
 
     // Extend TTL on access
     ttl::extend_user_ttl(env, user);
@@ -44,6 +108,85 @@ pub fn get_user(env: &Env, user: &Address) -> Result<User, SavingsError> {
     Ok(user_data)
 }
 
+/// Whether more than `threshold_seconds` have passed since the user's last
+/// deposit or withdrawal
+///
+/// Users with no recorded activity count from time 0; unknown addresses are
+/// never dormant.
+pub fn is_dormant(env: &Env, user: &Address, threshold_seconds: u64) -> bool {
+    match load_user(env, user) {
+        Some(user_data) => {
+            env.ledger()
+                .timestamp()
+                .saturating_sub(user_data.last_activity)
+                > threshold_seconds
+        }
+        None => false,
+    }
+}
+
+/// Returns the addresses in `users` that are dormant, in input order
+pub fn list_dormant_users(env: &Env, users: Vec<Address>, threshold_seconds: u64) -> Vec<Address> {
+    let mut dormant = Vec::new(env);
+    for user in users.iter() {
+        if is_dormant(env, &user, threshold_seconds) {
+            dormant.push_back(user);
+        }
+    }
+    dormant
+}
+
+/// Get the cumulative interest paid out to a user
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `user` - The address of the user
+///
+/// # Returns
+/// The user's lifetime interest, or 0 if the user does not exist
+pub fn get_lifetime_interest(env: &Env, user: &Address) -> i128 {
+    get_user(env, user)
+        .map(|u| u.lifetime_interest)
+        .unwrap_or(0)
+}
+
+/// Get a user's notification preferences
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `user` - The address of the user
+///
+/// # Returns
+/// The stored preferences, or `UserPrefs::default()` if none were set
+pub fn get_user_prefs(env: &Env, user: &Address) -> UserPrefs {
+    upgrade::read_versioned(env, &DataKey::UserPrefs(user.clone())).unwrap_or_default()
+}
+
+/// Store a user's notification preferences, replacing any previous ones
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `user` - The address of the user
+/// * `prefs` - The new preferences
+///
+/// # Returns
+/// `Ok(())` on success, `Err(SavingsError::UserNotFound)` if the user is not initialized
+///
+/// # Authorization
+/// Requires authorization from the user
+pub fn set_user_prefs(env: &Env, user: Address, prefs: UserPrefs) -> Result<(), SavingsError> {
+    user.require_auth();
+
+    if !user_exists(env, &user) {
+        return Err(SavingsError::UserNotFound);
+    }
+
+    upgrade::write_versioned(env, &DataKey::UserPrefs(user.clone()), &prefs);
+    ttl::extend_user_ttl(env, &user);
+
+    Ok(())
+}
+
 /// Initialize a new user in the savings contract
 ///
 /// This function creates a new user record with zero balances.
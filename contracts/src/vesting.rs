@@ -0,0 +1,369 @@
+use crate::errors::SavingsError;
+use crate::events;
+use crate::lock;
+use crate::rewards::storage;
+use crate::stats::{self, PlanKind};
+use crate::storage_types::DataKey;
+use crate::ttl::{EXTEND_ARCHIVED, EXTEND_TO, LOW_THRESHOLD};
+use crate::users;
+use crate::{ensure_not_paused, ensure_withdrawals_allowed};
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// A lock save whose principal unlocks linearly after a cliff.
+///
+/// Nothing can be claimed before `cliff_time`. From then on the vested
+/// portion grows linearly from `start_time` until `end_time`, at which
+/// point the full `amount` is claimable.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingLock {
+    pub id: u64,
+    pub owner: Address,
+    pub amount: i128,
+    pub claimed_amount: i128,
+    pub start_time: u64,
+    pub cliff_time: u64,
+    pub end_time: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VestingKey {
+    Lock(u64),
+    UserLocks(Address),
+    NextLockId,
+}
+
+/// Creates a vesting lock for `user`.
+///
+/// `cliff` and `duration` are both measured in seconds from creation and
+/// `cliff` may not exceed `duration`.
+pub fn create_vesting_lock(
+    env: &Env,
+    user: Address,
+    amount: i128,
+    cliff: u64,
+    duration: u64,
+) -> Result<u64, SavingsError> {
+    ensure_not_paused(env)?;
+    crate::config::ensure_plan_type_enabled(env, PlanKind::Lock)?;
+
+    if amount <= 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+    lock::validate_lock_amount(env, amount)?;
+    if duration == 0 || cliff > duration {
+        return Err(SavingsError::InvalidTimestamp);
+    }
+    if !users::user_exists(env, &user) {
+        return Err(SavingsError::UserNotFound);
+    }
+    lock::ensure_within_user_lock_cap(env, &user, amount)?;
+
+    let start_time = env.ledger().timestamp();
+    let cliff_time = start_time
+        .checked_add(cliff)
+        .ok_or(SavingsError::Overflow)?;
+    let end_time = start_time
+        .checked_add(duration)
+        .ok_or(SavingsError::Overflow)?;
+
+    let lock_id = next_lock_id(env);
+    let vesting = VestingLock {
+        id: lock_id,
+        owner: user.clone(),
+        amount,
+        claimed_amount: 0,
+        start_time,
+        cliff_time,
+        end_time,
+    };
+    save_vesting_lock(env, &vesting);
+
+    let list_key = VestingKey::UserLocks(user.clone());
+    let mut user_locks = get_user_vesting_locks(env, &user);
+    user_locks.push_back(lock_id);
+    env.storage().persistent().set(&list_key, &user_locks);
+    env.storage()
+        .persistent()
+        .extend_ttl(&list_key, LOW_THRESHOLD, EXTEND_TO);
+
+    stats::record_plan_opened(env, PlanKind::Lock, amount)?;
+
+    let user_key = DataKey::User(user.clone());
+    let mut user_data = users::load_user(env, &user).ok_or(SavingsError::UserNotFound)?;
+    user_data.total_balance = user_data
+        .total_balance
+        .checked_add(amount)
+        .ok_or(SavingsError::Overflow)?;
+    user_data.record_activity(env.ledger().timestamp(), true);
+    user_data.savings_count += 1;
+    env.storage().persistent().set(&user_key, &user_data);
+
+    storage::award_deposit_points(env, user.clone(), amount)?;
+    storage::award_long_lock_bonus(env, user.clone(), amount, duration)?;
+    stats::issue_deposit_receipt(env, &user, amount)?;
+
+    events::emit_vesting_created(env, user, lock_id, amount);
+
+    Ok(lock_id)
+}
+
+/// Pays out the vested-but-unclaimed portion of a vesting lock.
+///
+/// Returns 0 (without touching state) while nothing new has vested, e.g.
+/// before the cliff.
+pub fn claim_vested(env: &Env, user: Address, lock_id: u64) -> Result<i128, SavingsError> {
+    ensure_withdrawals_allowed(env)?;
+
+    let mut vesting = get_vesting_lock(env, lock_id).ok_or(SavingsError::PlanNotFound)?;
+
+    if vesting.owner != user {
+        return Err(SavingsError::Unauthorized);
+    }
+    if vesting.claimed_amount >= vesting.amount {
+        return Err(SavingsError::PlanCompleted);
+    }
+
+    let claimable = claimable_amount(&vesting, env.ledger().timestamp())?;
+    if claimable == 0 {
+        return Ok(0);
+    }
+
+    vesting.claimed_amount += claimable;
+    save_vesting_lock(env, &vesting);
+
+    if vesting.claimed_amount >= vesting.amount {
+        stats::record_plan_closed(env, PlanKind::Lock, claimable)?;
+    } else {
+        stats::record_principal_removed(env, PlanKind::Lock, claimable)?;
+    }
+
+    let user_key = DataKey::User(user.clone());
+    if let Some(mut user_data) = users::load_user(env, &user) {
+        user_data.total_balance -= claimable;
+        user_data.record_activity(env.ledger().timestamp(), false);
+        env.storage().persistent().set(&user_key, &user_data);
+    }
+
+    events::emit_vesting_claimed(env, user, lock_id, claimable);
+
+    Ok(claimable)
+}
+
+pub fn get_vesting_lock(env: &Env, lock_id: u64) -> Option<VestingLock> {
+    let key = VestingKey::Lock(lock_id);
+    let vesting: Option<VestingLock> = env.storage().persistent().get(&key);
+    if let Some(v) = &vesting {
+        extend_vesting_ttl(env, v);
+    }
+    vesting
+}
+
+pub fn get_user_vesting_locks(env: &Env, user: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&VestingKey::UserLocks(user.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Principal the user still holds across vesting locks, vested or not,
+/// that has not been claimed
+pub fn get_unclaimed_principal(env: &Env, user: &Address) -> Result<i128, SavingsError> {
+    let mut total = 0i128;
+    for lock_id in get_user_vesting_locks(env, user).iter() {
+        if let Some(vesting) = get_vesting_lock(env, lock_id) {
+            total = total
+                .checked_add(vesting.amount - vesting.claimed_amount)
+                .ok_or(SavingsError::Overflow)?;
+        }
+    }
+    Ok(total)
+}
+
+/// Amount that has vested at `now` but not yet been claimed.
+pub fn claimable_amount(vesting: &VestingLock, now: u64) -> Result<i128, SavingsError> {
+    Ok(vested_amount(vesting, now)? - vesting.claimed_amount)
+}
+
+// --- Internal Helper Functions ---
+
+fn vested_amount(vesting: &VestingLock, now: u64) -> Result<i128, SavingsError> {
+    if now < vesting.cliff_time {
+        return Ok(0);
+    }
+    if now >= vesting.end_time {
+        return Ok(vesting.amount);
+    }
+
+    let elapsed = (now - vesting.start_time) as i128;
+    let duration = (vesting.end_time - vesting.start_time) as i128;
+    vesting
+        .amount
+        .checked_mul(elapsed)
+        .map(|v| v / duration)
+        .ok_or(SavingsError::Overflow)
+}
+
+fn next_lock_id(env: &Env) -> u64 {
+    let key = VestingKey::NextLockId;
+    let id: u64 = env.storage().persistent().get(&key).unwrap_or(1);
+    env.storage().persistent().set(&key, &(id + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, LOW_THRESHOLD, EXTEND_TO);
+    id
+}
+
+fn save_vesting_lock(env: &Env, vesting: &VestingLock) {
+    env.storage()
+        .persistent()
+        .set(&VestingKey::Lock(vesting.id), vesting);
+    extend_vesting_ttl(env, vesting);
+}
+
+fn extend_vesting_ttl(env: &Env, vesting: &VestingLock) {
+    let extend_to = if vesting.claimed_amount >= vesting.amount {
+        EXTEND_ARCHIVED
+    } else {
+        EXTEND_TO
+    };
+    env.storage()
+        .persistent()
+        .extend_ttl(&VestingKey::Lock(vesting.id), LOW_THRESHOLD, extend_to);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NesteraContract, NesteraContractClient, SavingsError};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        Address, BytesN, Env,
+    };
+
+    fn setup() -> (Env, NesteraContractClient<'static>, Address) {
+        let env = Env::default();
+        let contract_id = env.register(NesteraContract, ());
+        let client = NesteraContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let admin_pk = BytesN::from_array(&env, &[1u8; 32]);
+
+        env.mock_all_auths();
+        client.initialize(&admin, &admin_pk);
+
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+
+        (env, client, user)
+    }
+
+    #[test]
+    fn test_claim_before_cliff_pays_nothing() {
+        let (env, client, user) = setup();
+        let lock_id = client.create_vesting_lock(&user, &10_000, &100, &1_000);
+
+        env.ledger().with_mut(|li| li.timestamp += 99);
+        assert_eq!(client.claim_vested(&user, &lock_id), 0);
+
+        let vesting = client.get_vesting_lock(&lock_id).unwrap();
+        assert_eq!(vesting.claimed_amount, 0);
+        assert_eq!(client.get_user(&user).total_balance, 10_000);
+    }
+
+    #[test]
+    fn test_claim_mid_vest_pays_partial() {
+        let (env, client, user) = setup();
+        let lock_id = client.create_vesting_lock(&user, &10_000, &100, &1_000);
+
+        env.ledger().with_mut(|li| li.timestamp += 250);
+        assert_eq!(client.claim_vested(&user, &lock_id), 2_500);
+
+        // Claiming again at the same time yields nothing new
+        assert_eq!(client.claim_vested(&user, &lock_id), 0);
+
+        env.ledger().with_mut(|li| li.timestamp += 250);
+        assert_eq!(client.claim_vested(&user, &lock_id), 2_500);
+
+        let vesting = client.get_vesting_lock(&lock_id).unwrap();
+        assert_eq!(vesting.claimed_amount, 5_000);
+        assert_eq!(client.get_user(&user).total_balance, 5_000);
+    }
+
+    #[test]
+    fn test_claim_after_duration_pays_full() {
+        let (env, client, user) = setup();
+        let lock_id = client.create_vesting_lock(&user, &10_000, &100, &1_000);
+
+        env.ledger().with_mut(|li| li.timestamp += 400);
+        assert_eq!(client.claim_vested(&user, &lock_id), 4_000);
+
+        env.ledger().with_mut(|li| li.timestamp += 5_000);
+        assert_eq!(client.claim_vested(&user, &lock_id), 6_000);
+
+        let vesting = client.get_vesting_lock(&lock_id).unwrap();
+        assert_eq!(vesting.claimed_amount, 10_000);
+        assert_eq!(client.get_user(&user).total_balance, 0);
+
+        let stats = client.get_plan_type_stats();
+        assert_eq!(stats.lock.count, 0);
+        assert_eq!(stats.lock.total_principal, 0);
+
+        assert_eq!(
+            client.try_claim_vested(&user, &lock_id),
+            Err(Ok(SavingsError::PlanCompleted))
+        );
+    }
+
+    #[test]
+    fn test_vesting_lock_counts_toward_user_lock_cap() {
+        let env = Env::default();
+        let contract_id = env.register(NesteraContract, ());
+        let client = NesteraContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+        client.initialize(&admin, &BytesN::from_array(&env, &[1u8; 32]));
+        let user = Address::generate(&env);
+        client.initialize_user(&user);
+        client.set_max_total_locked_per_user(&admin, &Some(15_000));
+
+        client.create_lock_save(&user, &5_000, &86_400);
+        let vesting_id = client.create_vesting_lock(&user, &8_000, &0, &1_000);
+        assert_eq!(
+            client.try_create_vesting_lock(&user, &2_001, &0, &1_000),
+            Err(Ok(SavingsError::InvalidAmount))
+        );
+        assert_eq!(
+            client.try_create_lock_save(&user, &2_001, &86_400),
+            Err(Ok(SavingsError::InvalidAmount))
+        );
+
+        // Claimed vesting principal frees room under the cap
+        env.ledger().with_mut(|li| li.timestamp += 500);
+        assert_eq!(client.claim_vested(&user, &vesting_id), 4_000);
+        assert!(client
+            .try_create_vesting_lock(&user, &6_000, &0, &1_000)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_create_vesting_lock_rejects_cliff_after_duration() {
+        let (_env, client, user) = setup();
+        assert_eq!(
+            client.try_create_vesting_lock(&user, &10_000, &2_000, &1_000),
+            Err(Ok(SavingsError::InvalidTimestamp))
+        );
+    }
+
+    #[test]
+    fn test_claim_vested_rejects_non_owner() {
+        let (env, client, user) = setup();
+        let other = Address::generate(&env);
+        client.initialize_user(&other);
+        let lock_id = client.create_vesting_lock(&user, &10_000, &0, &1_000);
+
+        assert_eq!(
+            client.try_claim_vested(&other, &lock_id),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+    }
+}
@@ -1,8 +1,9 @@
 use crate::errors::SavingsError;
 use crate::storage_types::{
-    DataKey, GoalSaveView, GroupSaveView, LockSaveView, PlanType, SavingsPlan, User,
+    DataKey, GoalSaveView, GroupSaveView, InterestBreakdown, LockSaveView, PlanType, SavingsPlan,
+    User,
 };
-use crate::ttl;
+use crate::{flexi, goal, group, lock, ttl, users, vesting};
 use soroban_sdk::{Address, Env, Vec};
 
 // ===========================================================================
@@ -347,3 +348,102 @@ pub fn get_group_member_contribution(
 
     Err(SavingsError::PlanNotFound)
 }
+
+// ===========================================================================
+// Interest
+// ===========================================================================
+
+/// Breaks the user's accrued interest down by plan type at the current time.
+///
+/// Goals pay out their principal only, so they have no component.
+pub fn get_interest_breakdown(env: &Env, user: Address) -> Result<InterestBreakdown, SavingsError> {
+    if !users::user_exists(env, &user) {
+        return Err(SavingsError::UserNotFound);
+    }
+
+    let flexi = flexi::pending_flexi_interest(env, &user);
+
+    let mut lock = 0i128;
+    for lock_id in lock::get_user_lock_saves(env, &user).iter() {
+        let accrued = lock::preview_lock_interest(env, lock_id).unwrap_or(0);
+        lock = lock.checked_add(accrued).ok_or(SavingsError::Overflow)?;
+    }
+
+    let total = flexi.checked_add(lock).ok_or(SavingsError::Overflow)?;
+
+    Ok(InterestBreakdown { flexi, lock, total })
+}
+
+// ===========================================================================
+// Health Checks
+// ===========================================================================
+
+/// Checks the user's stored aggregates against their plans.
+///
+/// `total_balance` must equal the sum of everything that moves it: the flexi
+/// balance, the principal of unwithdrawn locks, the unclaimed part of vesting
+/// locks, open savings plan balances, group contributions and the goal
+/// payouts credited to the user.
+///
+/// `savings_count` is a lifetime count, bumped for every plan opened and
+/// never lowered when one closes, so it can't fall below the number of plans
+/// still open. Returns `false` on any drift.
+pub fn verify_invariants(env: &Env, user: Address) -> Result<bool, SavingsError> {
+    let user_data = users::get_user(env, &user)?;
+
+    let mut tracked = flexi::get_flexi_balance(env, user.clone())?
+        .checked_add(goal::get_goal_payouts(env, &user))
+        .ok_or(SavingsError::Overflow)?;
+    let mut open_plans: u32 = 0;
+    for lock_id in lock::get_user_lock_saves(env, &user).iter() {
+        if let Some(lock_save) = lock::get_lock_save(env, lock_id) {
+            if !lock_save.is_withdrawn {
+                tracked = tracked
+                    .checked_add(lock_save.amount)
+                    .ok_or(SavingsError::Overflow)?;
+                open_plans += 1;
+            }
+        }
+    }
+    for lock_id in vesting::get_user_vesting_locks(env, &user).iter() {
+        if let Some(vesting_lock) = vesting::get_vesting_lock(env, lock_id) {
+            if vesting_lock.claimed_amount < vesting_lock.amount {
+                tracked = tracked
+                    .checked_add(vesting_lock.amount - vesting_lock.claimed_amount)
+                    .ok_or(SavingsError::Overflow)?;
+                open_plans += 1;
+            }
+        }
+    }
+    // Goal principal only reaches `total_balance` when paid out
+    for goal_id in goal::get_user_goal_saves(env, &user).iter() {
+        if let Some(goal_save) = goal::get_goal_save(env, goal_id) {
+            if !goal_save.is_withdrawn {
+                open_plans += 1;
+            }
+        }
+    }
+    // Group members hold a `SavingsPlan` under the group ID; those are
+    // skipped here and their contributions summed below instead
+    for plan_id in 1..=user_data.savings_count as u64 {
+        let key = DataKey::SavingsPlan(user.clone(), plan_id);
+        if let Some(plan) = env.storage().persistent().get::<DataKey, SavingsPlan>(&key) {
+            if plan.plan_id == plan_id
+                && !plan.is_withdrawn
+                && !matches!(plan.plan_type, PlanType::Group(..))
+            {
+                tracked = tracked
+                    .checked_add(plan.balance)
+                    .ok_or(SavingsError::Overflow)?;
+                open_plans += 1;
+            }
+        }
+    }
+    for group_id in group::get_user_groups(env, &user).iter() {
+        tracked = tracked
+            .checked_add(group::get_member_contribution(env, group_id, &user))
+            .ok_or(SavingsError::Overflow)?;
+    }
+
+    Ok(user_data.total_balance == tracked && user_data.savings_count >= open_plans)
+}
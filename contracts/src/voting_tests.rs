@@ -1,8 +1,9 @@
 #[cfg(test)]
 mod voting_tests {
 
+    use crate::governance::VotingPowerSource;
     use crate::rewards::storage_types::RewardsConfig;
-    use crate::{NesteraContract, NesteraContractClient, PlanType};
+    use crate::{NesteraContract, NesteraContractClient, PlanType, SavingsError};
     use soroban_sdk::{
         testutils::{Address as _, Ledger},
         Address, BytesN, Env, String,
@@ -244,4 +245,59 @@ mod voting_tests {
         let proposal = client.get_proposal(&proposal_id).unwrap();
         assert_eq!(proposal.for_votes, 8000);
     }
+
+    #[test]
+    fn test_voting_power_follows_configured_source() {
+        let (env, client, admin) = setup_contract();
+        let user = Address::generate(&env);
+        env.mock_all_auths();
+
+        client.initialize_user(&user);
+        let _ = client.create_savings_plan(&user, &PlanType::Flexi, &1000);
+        client.withdraw_flexi(&user, &400);
+        client.create_lock_save(&user, &2000, &(365 * 24 * 60 * 60));
+
+        assert_eq!(
+            client.get_voting_power_source(),
+            VotingPowerSource::LifetimeDeposited
+        );
+        assert_eq!(client.get_voting_power(&user), 3000);
+
+        client.set_voting_power_source(&admin, &VotingPowerSource::CurrentTotalBalance);
+        assert_eq!(client.get_voting_power(&user), 2600);
+
+        client.set_voting_power_source(&admin, &VotingPowerSource::CurrentLocked);
+        assert_eq!(client.get_voting_power(&user), 2000);
+
+        let other = Address::generate(&env);
+        assert_eq!(
+            client.try_set_voting_power_source(&other, &VotingPowerSource::LifetimeDeposited),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+
+        // Once governance is live the source can only change by proposal
+        client.activate_governance(&admin);
+        assert_eq!(
+            client.try_set_voting_power_source(&admin, &VotingPowerSource::LifetimeDeposited),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_get_user_rewards_reflects_recorded_deposits() {
+        let (env, client, _) = setup_contract();
+        let user = Address::generate(&env);
+        env.mock_all_auths();
+
+        assert_eq!(client.get_user_rewards(&user).lifetime_deposited, 0);
+
+        client.initialize_user(&user);
+        let _ = client.create_savings_plan(&user, &PlanType::Flexi, &1000);
+        let _ = client.create_savings_plan(&user, &PlanType::Flexi, &500);
+
+        let rewards = client.get_user_rewards(&user);
+        assert_eq!(rewards.lifetime_deposited, 1500);
+        assert_eq!(rewards, client.get_user_rewards(&user));
+        assert_eq!(client.get_voting_power(&user), 1500);
+    }
 }
@@ -1,7 +1,10 @@
 #[cfg(test)]
 mod autosave_tests {
-    use soroban_sdk::{testutils::Address as _, Address, Env};
-    use Nestera::{NesteraContract, NesteraContractClient};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        Address, Env,
+    };
+    use Nestera::{AutoSave, DataKey, NesteraContract, NesteraContractClient, SavingsError};
 
     fn setup_test_contract() -> (Env, NesteraContractClient<'static>, Address) {
         let env = Env::default();
@@ -35,6 +38,21 @@ mod autosave_tests {
         assert_eq!(schedules.get(0).unwrap(), 1);
     }
 
+    #[test]
+    fn test_create_autosave_v2_returns_receipt_matching_schedule() {
+        let (env, client, user) = setup_test_contract();
+
+        let start_time = env.ledger().timestamp() + 3600;
+        let receipt = client.create_autosave_v2(&user, &1000, &86400, &start_time);
+
+        let schedule = client.get_autosave(&receipt.schedule_id).unwrap();
+        assert_eq!(receipt.schedule_id, schedule.id);
+        assert_eq!(receipt.first_execution_time, schedule.next_execution_time);
+        assert_eq!(receipt.first_execution_time, start_time);
+        assert_eq!(receipt.interval_seconds, schedule.interval_seconds);
+        assert_eq!(receipt.interval_seconds, 86400);
+    }
+
     #[test]
     fn test_create_autosave_zero_amount() {
         let (env, client, user) = setup_test_contract();
@@ -52,7 +70,30 @@ mod autosave_tests {
         let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
             client.create_autosave(&user, &1000, &0, &env.ledger().timestamp());
         }));
-        assert!(result.is_err()); // Should panic with InvalidTimestamp
+        assert!(result.is_err()); // Should panic with InvalidDuration
+    }
+
+    #[test]
+    fn test_create_autosave_zero_interval_is_invalid_duration() {
+        let (env, client, user) = setup_test_contract();
+
+        let result = client.try_create_autosave(&user, &1000, &0, &env.ledger().timestamp());
+        assert_eq!(result, Err(Ok(SavingsError::InvalidDuration)));
+    }
+
+    #[test]
+    fn test_create_autosave_start_time_far_in_past() {
+        let (env, client, user) = setup_test_contract();
+        env.ledger().with_mut(|li| li.timestamp = 1_000_000);
+
+        // A full interval behind is rejected as a bad timestamp
+        let result = client.try_create_autosave(&user, &1000, &86400, &(1_000_000 - 86400));
+        assert_eq!(result, Err(Ok(SavingsError::InvalidTimestamp)));
+
+        // Slightly in the past is still accepted
+        assert!(client
+            .try_create_autosave(&user, &1000, &86400, &(1_000_000 - 3600))
+            .is_ok());
     }
 
     #[test]
@@ -350,4 +391,492 @@ mod autosave_tests {
         assert_eq!(client.get_flexi_balance(&user1), 500);
         assert_eq!(client.get_flexi_balance(&user2), 800);
     }
+
+    #[test]
+    fn test_get_all_autosaves_pages_across_users() {
+        let (env, client, user1) = setup_test_contract();
+
+        let user2 = Address::generate(&env);
+        let user3 = Address::generate(&env);
+        client.initialize_user(&user2);
+        client.initialize_user(&user3);
+
+        let start_time = env.ledger().timestamp();
+        let id1 = client.create_autosave(&user1, &100, &86400, &start_time);
+        let id2 = client.create_autosave(&user2, &200, &86400, &start_time);
+        let id3 = client.create_autosave(&user3, &300, &86400, &start_time);
+        let id4 = client.create_autosave(&user1, &400, &3600, &start_time);
+
+        let all = client.get_all_autosaves(&0, &10);
+        assert_eq!(all, soroban_sdk::vec![&env, id1, id2, id3, id4]);
+
+        let first_page = client.get_all_autosaves(&0, &3);
+        assert_eq!(first_page, soroban_sdk::vec![&env, id1, id2, id3]);
+
+        let second_page = client.get_all_autosaves(&3, &3);
+        assert_eq!(second_page, soroban_sdk::vec![&env, id4]);
+
+        assert_eq!(client.get_all_autosaves(&4, &3).len(), 0);
+    }
+
+    #[test]
+    fn test_cancelled_autosave_removed_from_user_listing() {
+        let (env, client, user) = setup_test_contract();
+
+        let start_time = env.ledger().timestamp();
+        let id1 = client.create_autosave(&user, &1000, &86400, &start_time);
+        let id2 = client.create_autosave(&user, &2000, &86400, &start_time);
+
+        client.cancel_autosave(&user, &id1);
+
+        let schedules = client.get_user_autosaves(&user);
+        assert_eq!(schedules, soroban_sdk::vec![&env, id2]);
+
+        // The cancelled record remains readable
+        let cancelled = client.get_autosave(&id1).unwrap();
+        assert!(!cancelled.is_active);
+
+        // Cancelling again is harmless
+        client.cancel_autosave(&user, &id1);
+        assert_eq!(client.get_user_autosaves(&user).len(), 1);
+    }
+
+    #[test]
+    fn test_keeper_execute_autosave_pays_reward() {
+        let (env, client, user) = setup_test_contract();
+        let admin = Address::generate(&env);
+        let admin_pk = soroban_sdk::BytesN::from_array(&env, &[1u8; 32]);
+        client.initialize(&admin, &admin_pk);
+
+        assert_eq!(client.get_keeper_reward_rate(), 0);
+        client.set_keeper_reward_rate(&50); // 0.5%
+        assert_eq!(client.get_keeper_reward_rate(), 50);
+
+        let keeper = Address::generate(&env);
+        let schedule_id = client.create_autosave(&user, &10_000, &86400, &env.ledger().timestamp());
+
+        let reward = client.keeper_execute_autosave(&keeper, &schedule_id);
+        assert_eq!(reward, 50);
+        assert_eq!(client.get_keeper_rewards(&keeper), 50);
+        assert_eq!(client.get_flexi_balance(&user), 9_950);
+    }
+
+    #[test]
+    fn test_plain_execute_autosave_pays_no_reward() {
+        let (env, client, user) = setup_test_contract();
+        let admin = Address::generate(&env);
+        let admin_pk = soroban_sdk::BytesN::from_array(&env, &[1u8; 32]);
+        client.initialize(&admin, &admin_pk);
+        client.set_keeper_reward_rate(&50);
+
+        let schedule_id = client.create_autosave(&user, &10_000, &86400, &env.ledger().timestamp());
+        client.execute_autosave(&schedule_id);
+
+        assert_eq!(client.get_flexi_balance(&user), 10_000);
+    }
+
+    #[test]
+    fn test_cancel_autosaves_skips_foreign_ids() {
+        let (env, client, user) = setup_test_contract();
+        let other = Address::generate(&env);
+        client.initialize_user(&other);
+
+        let start_time = env.ledger().timestamp();
+        let own1 = client.create_autosave(&user, &100, &86400, &start_time);
+        let foreign = client.create_autosave(&other, &200, &86400, &start_time);
+        let own2 = client.create_autosave(&user, &300, &86400, &start_time);
+
+        let ids = soroban_sdk::vec![&env, own1, foreign, 999, own2];
+        let cancelled = client.cancel_autosaves(&user, &ids);
+        assert_eq!(cancelled, soroban_sdk::vec![&env, own1, own2]);
+
+        assert!(!client.get_autosave(&own1).unwrap().is_active);
+        assert!(!client.get_autosave(&own2).unwrap().is_active);
+        assert!(client.get_autosave(&foreign).unwrap().is_active);
+        assert_eq!(client.get_user_autosaves(&user).len(), 0);
+        assert_eq!(client.get_user_autosaves(&other).len(), 1);
+    }
+
+    fn setup_with_admin() -> (Env, NesteraContractClient<'static>, Address) {
+        let (env, client, user) = setup_test_contract();
+        let admin = Address::generate(&env);
+        let admin_pk = soroban_sdk::BytesN::from_array(&env, &[1u8; 32]);
+        client.initialize(&admin, &admin_pk);
+        (env, client, user)
+    }
+
+    #[test]
+    fn test_execute_autosave_permissionless_without_keepers() {
+        let (env, client, user) = setup_with_admin();
+        assert_eq!(client.get_keepers().len(), 0);
+
+        let schedule_id = client.create_autosave(&user, &1000, &86400, &env.ledger().timestamp());
+        client.execute_autosave(&schedule_id);
+
+        let anyone = Address::generate(&env);
+        env.ledger().with_mut(|li| li.timestamp += 86400);
+        client.keeper_execute_autosave(&anyone, &schedule_id);
+
+        assert_eq!(client.get_flexi_balance(&user), 2000);
+    }
+
+    #[test]
+    fn test_whitelisted_keeper_can_execute() {
+        let (env, client, user) = setup_with_admin();
+        let keeper = Address::generate(&env);
+        client.add_keeper(&keeper);
+        assert_eq!(
+            client.get_keepers(),
+            soroban_sdk::vec![&env, keeper.clone()]
+        );
+
+        let schedule_id = client.create_autosave(&user, &1000, &86400, &env.ledger().timestamp());
+        client.keeper_execute_autosave(&keeper, &schedule_id);
+
+        assert_eq!(client.get_flexi_balance(&user), 1000);
+    }
+
+    #[test]
+    fn test_non_keeper_rejected_when_whitelist_set() {
+        let (env, client, user) = setup_with_admin();
+        let keeper = Address::generate(&env);
+        let stranger = Address::generate(&env);
+        client.add_keeper(&keeper);
+
+        let schedule_id = client.create_autosave(&user, &1000, &86400, &env.ledger().timestamp());
+
+        assert_eq!(
+            client.try_keeper_execute_autosave(&stranger, &schedule_id),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_execute_autosave(&schedule_id),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+        let results = client.execute_due_autosaves(&soroban_sdk::vec![&env, schedule_id]);
+        assert!(!results.get(0).unwrap());
+        assert_eq!(client.get_flexi_balance(&user), 0);
+
+        // Removing the last keeper restores permissionless execution
+        client.remove_keeper(&keeper);
+        client.execute_autosave(&schedule_id);
+        assert_eq!(client.get_flexi_balance(&user), 1000);
+    }
+
+    #[test]
+    fn test_reactivate_cancelled_autosave_executes_again() {
+        let (env, client, user) = setup_test_contract();
+
+        let start_time = env.ledger().timestamp();
+        let schedule_id = client.create_autosave(&user, &1000, &86400, &start_time);
+        client.execute_autosave(&schedule_id);
+        client.cancel_autosave(&user, &schedule_id);
+        assert!(client.try_execute_autosave(&schedule_id).is_err());
+
+        // Three and a half intervals later the missed slots are skipped
+        env.ledger()
+            .with_mut(|li| li.timestamp = start_time + 3 * 86400 + 43200);
+        client.reactivate_autosave(&user, &schedule_id);
+
+        let schedule = client.get_autosave(&schedule_id).unwrap();
+        assert!(schedule.is_active);
+        assert_eq!(schedule.next_execution_time, start_time + 4 * 86400);
+        assert_eq!(
+            client.get_user_autosaves(&user),
+            soroban_sdk::vec![&env, schedule_id]
+        );
+
+        assert!(client.try_execute_autosave(&schedule_id).is_err());
+        env.ledger()
+            .with_mut(|li| li.timestamp = start_time + 4 * 86400);
+        client.execute_autosave(&schedule_id);
+        assert_eq!(client.get_flexi_balance(&user), 2000);
+    }
+
+    #[test]
+    fn test_reactivate_autosave_rejects_non_owner_and_active() {
+        let (env, client, user) = setup_test_contract();
+        let other = Address::generate(&env);
+        client.initialize_user(&other);
+
+        let schedule_id = client.create_autosave(&user, &1000, &86400, &env.ledger().timestamp());
+        assert_eq!(
+            client.try_reactivate_autosave(&user, &schedule_id),
+            Err(Ok(SavingsError::InvalidPlanConfig))
+        );
+
+        client.cancel_autosave(&user, &schedule_id);
+        assert_eq!(
+            client.try_reactivate_autosave(&other, &schedule_id),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+        assert!(!client.get_autosave(&schedule_id).unwrap().is_active);
+    }
+
+    #[test]
+    fn test_autosave_overflowing_flexi_balance_errors_cleanly() {
+        let (env, client, user) = setup_test_contract();
+
+        let amount = i128::MAX / 2 + 1;
+        let start_time = env.ledger().timestamp();
+        let schedule_id = client.create_autosave(&user, &amount, &1, &start_time);
+
+        client.execute_autosave(&schedule_id);
+        assert_eq!(client.get_flexi_balance(&user), amount);
+
+        env.ledger().with_mut(|li| li.timestamp += 1);
+        assert_eq!(
+            client.try_execute_autosave(&schedule_id),
+            Err(Ok(SavingsError::Overflow))
+        );
+        assert_eq!(client.get_flexi_balance(&user), amount);
+        assert_eq!(
+            client
+                .get_autosave(&schedule_id)
+                .unwrap()
+                .next_execution_time,
+            start_time + 1
+        );
+    }
+
+    #[test]
+    fn test_peek_next_autosave_id_matches_created_id() {
+        let (env, client, user) = setup_test_contract();
+
+        let peeked = client.peek_next_autosave_id();
+        assert_eq!(peeked, 1);
+        let id = client.create_autosave(&user, &1000, &86400, &env.ledger().timestamp());
+        assert_eq!(id, peeked);
+        assert_eq!(client.peek_next_autosave_id(), peeked + 1);
+    }
+
+    #[test]
+    fn test_autosave_below_min_flexi_deposit() {
+        let (env, client, user) = setup_test_contract();
+        let admin = Address::generate(&env);
+        let admin_pk = soroban_sdk::BytesN::from_array(&env, &[1u8; 32]);
+        client.initialize(&admin, &admin_pk);
+
+        let start_time = env.ledger().timestamp();
+        let schedule_id = client.create_autosave(&user, &1000, &86400, &start_time);
+
+        client.set_min_flexi_deposit(&admin, &Some(2000));
+
+        // New schedules below the minimum are rejected up front
+        assert_eq!(
+            client.try_create_autosave(&user, &1999, &86400, &start_time),
+            Err(Ok(SavingsError::InvalidAmount))
+        );
+
+        // Existing ones fail to execute and are skipped in batches
+        assert_eq!(
+            client.try_execute_autosave(&schedule_id),
+            Err(Ok(SavingsError::InvalidAmount))
+        );
+        let results = client.execute_due_autosaves(&soroban_sdk::vec![&env, schedule_id]);
+        assert_eq!(results, soroban_sdk::vec![&env, false]);
+        assert_eq!(client.get_flexi_balance(&user), 0);
+    }
+
+    #[test]
+    fn test_batch_execute_duplicate_ids_run_once() {
+        let (env, client, user) = setup_test_contract();
+
+        let schedule_id = client.create_autosave(&user, &1000, &86400, &env.ledger().timestamp());
+
+        // Several intervals overdue, so a second pass would otherwise be due too
+        env.ledger().with_mut(|li| li.timestamp += 3 * 86400);
+
+        let ids = soroban_sdk::vec![&env, schedule_id, schedule_id];
+        let results = client.execute_due_autosaves(&ids);
+        assert_eq!(results, soroban_sdk::vec![&env, true, false]);
+        assert_eq!(client.get_flexi_balance(&user), 1000);
+    }
+
+    #[test]
+    fn test_pause_and_resume_all_autosaves_for_one_user() {
+        let (env, client, user) = setup_test_contract();
+        let other = Address::generate(&env);
+        client.initialize_user(&other);
+
+        let start = env.ledger().timestamp() + 3600;
+        let id1 = client.create_autosave(&user, &1000, &86400, &start);
+        let id2 = client.create_autosave(&user, &500, &(7 * 86400), &start);
+        let others = client.create_autosave(&other, &300, &86400, &start);
+
+        let paused = client.pause_all_autosaves(&user);
+        assert_eq!(paused, soroban_sdk::vec![&env, id1, id2]);
+        assert!(client.is_autosave_paused(&id1));
+        assert!(client.is_autosave_paused(&id2));
+        assert!(client.get_autosave(&id1).unwrap().is_active);
+        assert!(!client.is_autosave_paused(&others));
+        assert_eq!(client.get_user_autosaves(&user).len(), 2);
+        assert_eq!(client.next_autosave_run(&id1), None);
+
+        // Nothing runs while paused
+        env.ledger()
+            .with_mut(|li| li.timestamp = start + 2 * 86400 + 10);
+        let results = client.execute_due_autosaves(&soroban_sdk::vec![&env, id1, id2, others]);
+        assert_eq!(results, soroban_sdk::vec![&env, false, false, true]);
+        assert_eq!(client.get_flexi_balance(&user), 0);
+
+        // Missed runs are skipped; each schedule keeps its cadence
+        let resumed = client.resume_all_autosaves(&user);
+        assert_eq!(resumed, soroban_sdk::vec![&env, id1, id2]);
+        let s1 = client.get_autosave(&id1).unwrap();
+        let s2 = client.get_autosave(&id2).unwrap();
+        assert!(!client.is_autosave_paused(&id1) && !client.is_autosave_paused(&id2));
+        assert_eq!(s1.next_execution_time, start + 3 * 86400);
+        assert_eq!(s2.next_execution_time, start + 7 * 86400);
+        assert_eq!(
+            client.get_autosave(&others).unwrap().next_execution_time,
+            start + 86400
+        );
+
+        // Resuming again is a no-op
+        assert!(client.resume_all_autosaves(&user).is_empty());
+    }
+
+    #[test]
+    fn test_resume_all_autosaves_leaves_cancelled_schedules_alone() {
+        let (env, client, user) = setup_test_contract();
+
+        let start = env.ledger().timestamp() + 3600;
+        let live = client.create_autosave(&user, &1000, &86400, &start);
+        let cancelled = client.create_autosave(&user, &500, &86400, &start);
+        client.cancel_autosave(&user, &cancelled);
+
+        assert_eq!(
+            client.pause_all_autosaves(&user),
+            soroban_sdk::vec![&env, live]
+        );
+        assert_eq!(
+            client.resume_all_autosaves(&user),
+            soroban_sdk::vec![&env, live]
+        );
+        assert!(!client.get_autosave(&cancelled).unwrap().is_active);
+
+        // Cancelling a paused schedule clears the pause with it
+        client.pause_all_autosaves(&user);
+        client.cancel_autosave(&user, &live);
+        assert!(!client.is_autosave_paused(&live));
+        assert!(client.resume_all_autosaves(&user).is_empty());
+    }
+
+    #[test]
+    fn test_reactivate_autosave_does_not_duplicate_index_entry() {
+        let (env, client, user) = setup_test_contract();
+
+        let schedule_id = client.create_autosave(&user, &1000, &86400, &env.ledger().timestamp());
+
+        // A schedule cancelled before cancellation unlinked it from the index
+        env.as_contract(&client.address, || {
+            let key = DataKey::AutoSave(schedule_id);
+            let mut schedule: AutoSave = env.storage().persistent().get(&key).unwrap();
+            schedule.is_active = false;
+            env.storage().persistent().set(&key, &schedule);
+        });
+
+        client.reactivate_autosave(&user, &schedule_id);
+        assert_eq!(
+            client.get_user_autosaves(&user),
+            soroban_sdk::vec![&env, schedule_id]
+        );
+    }
+
+    #[test]
+    fn test_next_autosave_run() {
+        let (env, client, user) = setup_test_contract();
+
+        let start_time = env.ledger().timestamp() + 3600;
+        let schedule_id = client.create_autosave(&user, &1000, &86400, &start_time);
+        assert_eq!(client.next_autosave_run(&schedule_id), Some(start_time));
+
+        env.ledger().with_mut(|li| li.timestamp = start_time);
+        client.execute_autosave(&schedule_id);
+        assert_eq!(
+            client.next_autosave_run(&schedule_id),
+            Some(start_time + 86400)
+        );
+
+        client.cancel_autosave(&user, &schedule_id);
+        assert_eq!(client.next_autosave_run(&schedule_id), None);
+
+        assert_eq!(client.next_autosave_run(&999), None);
+    }
+
+    #[test]
+    fn test_create_autosave_now_first_run_after_one_interval() {
+        let (env, client, user) = setup_test_contract();
+        env.ledger().with_mut(|li| li.timestamp = 5_000);
+
+        let schedule_id = client.create_autosave_now(&user, &1000, &3600);
+        assert_eq!(client.next_autosave_run(&schedule_id), Some(8_600));
+
+        env.ledger().with_mut(|li| li.timestamp = 8_599);
+        assert_eq!(
+            client.try_execute_autosave(&schedule_id),
+            Err(Ok(SavingsError::InvalidTimestamp))
+        );
+
+        env.ledger().with_mut(|li| li.timestamp = 8_600);
+        client.execute_autosave(&schedule_id);
+        assert_eq!(client.get_flexi_balance(&user), 1000);
+    }
+
+    #[test]
+    fn test_update_autosave_keeps_next_run() {
+        let (env, client, user) = setup_test_contract();
+
+        let start_time = env.ledger().timestamp() + 86400;
+        let schedule_id = client.create_autosave(&user, &1000, &86400, &start_time);
+
+        // Halfway to the first run, switch to a weekly cadence
+        env.ledger()
+            .with_mut(|li| li.timestamp = start_time - 43200);
+        client.update_autosave(&user, &schedule_id, &2000, &604800);
+        assert_eq!(client.next_autosave_run(&schedule_id), Some(start_time));
+
+        // First run still happens at the originally scheduled time
+        env.ledger().with_mut(|li| li.timestamp = start_time);
+        client.execute_autosave(&schedule_id);
+        assert_eq!(client.get_flexi_balance(&user), 2000);
+
+        // Following run uses the new interval
+        assert_eq!(
+            client.next_autosave_run(&schedule_id),
+            Some(start_time + 604800)
+        );
+        env.ledger()
+            .with_mut(|li| li.timestamp = start_time + 86400);
+        assert_eq!(
+            client.try_execute_autosave(&schedule_id),
+            Err(Ok(SavingsError::InvalidTimestamp))
+        );
+    }
+
+    #[test]
+    fn test_update_autosave_validation() {
+        let (env, client, user) = setup_test_contract();
+        let schedule_id = client.create_autosave(&user, &1000, &86400, &env.ledger().timestamp());
+
+        assert_eq!(
+            client.try_update_autosave(&user, &schedule_id, &0, &86400),
+            Err(Ok(SavingsError::InvalidAmount))
+        );
+        assert_eq!(
+            client.try_update_autosave(&user, &schedule_id, &1000, &0),
+            Err(Ok(SavingsError::InvalidDuration))
+        );
+
+        let stranger = Address::generate(&env);
+        assert_eq!(
+            client.try_update_autosave(&stranger, &schedule_id, &1000, &3600),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_update_autosave(&user, &999, &1000, &3600),
+            Err(Ok(SavingsError::PlanNotFound))
+        );
+    }
 }
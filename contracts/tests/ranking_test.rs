@@ -272,3 +272,34 @@ fn test_ranking_read_only() {
     let rank2 = client.get_user_rank(&users.get(0).unwrap());
     assert_eq!(rank1, rank2, "Rank should be consistent");
 }
+
+#[test]
+fn test_get_top_savers_orders_and_truncates() {
+    let (env, client, admin, users) = create_test_env();
+    setup_rewards_config(&client, &admin);
+
+    let amounts = [3_000i128, 1_000, 5_000, 2_000];
+    for (i, amount) in amounts.iter().enumerate() {
+        client.deposit_flexi(&users.get(i as u32).unwrap(), amount);
+    }
+
+    // users[4] never deposited; users[0] is listed twice
+    let mut candidates = Vec::new(&env);
+    for i in [0u32, 1, 2, 3, 4, 0] {
+        candidates.push_back(users.get(i).unwrap());
+    }
+
+    let all = client.get_top_savers(&candidates, &10);
+    assert_eq!(all.len(), 4);
+    assert_eq!(all.get(0).unwrap(), (users.get(2).unwrap(), 5_000));
+    assert_eq!(all.get(1).unwrap(), (users.get(0).unwrap(), 3_000));
+    assert_eq!(all.get(2).unwrap(), (users.get(3).unwrap(), 2_000));
+    assert_eq!(all.get(3).unwrap(), (users.get(1).unwrap(), 1_000));
+
+    let top_two = client.get_top_savers(&candidates, &2);
+    assert_eq!(top_two.len(), 2);
+    assert_eq!(top_two.get(0).unwrap().0, users.get(2).unwrap());
+    assert_eq!(top_two.get(1).unwrap().0, users.get(0).unwrap());
+
+    assert_eq!(client.get_top_savers(&candidates, &0).len(), 0);
+}
@@ -1,7 +1,10 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
-use Nestera::{NesteraContract, NesteraContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env, Symbol,
+};
+use Nestera::{NesteraContract, NesteraContractClient, SavingsError};
 
 fn create_test_env() -> (Env, NesteraContractClient<'static>, Address, Address) {
     let env = Env::default();
@@ -257,3 +260,81 @@ fn test_redemption_overflow_protection() {
     let rewards_after = client.get_user_rewards(&user);
     assert_eq!(rewards_after.total_points, rewards.total_points);
 }
+
+#[test]
+fn test_redeemed_points_discount_next_early_break() {
+    let (env, client, admin, user) = create_test_env();
+    setup_rewards_config(&env, &client, &admin);
+    let treasury = Address::generate(&env);
+    client.set_fee_recipient(&treasury);
+    client.set_early_break_fee_bps(&1_000); // 10%
+
+    add_points_directly(&env, &client, &user, 500_000);
+
+    // 250,000 points buy a 2,500 bps (25%) discount
+    client.redeem_points(&user, &250_000);
+    assert_eq!(client.get_penalty_discount(&user), 2_500);
+
+    let goal_name = Symbol::new(&env, "trip");
+    let goal_id = client.create_goal_save(&user, &goal_name, &10_000, &2_000);
+    let net = client.break_goal_save(&user, &goal_id);
+
+    // fee = 200, discounted by 25% to 150
+    assert_eq!(net, 1_850);
+    assert_eq!(client.get_protocol_fee_balance(&treasury), 150);
+
+    // The discount is spent; the next break pays the full fee
+    assert_eq!(client.get_penalty_discount(&user), 0);
+    let goal_id = client.create_goal_save(&user, &goal_name, &10_000, &2_000);
+    assert_eq!(client.break_goal_save(&user, &goal_id), 1_800);
+    assert_eq!(client.get_protocol_fee_balance(&treasury), 350);
+}
+
+#[test]
+fn test_redeem_points_cannot_exceed_full_waiver() {
+    let (env, client, admin, user) = create_test_env();
+    setup_rewards_config(&env, &client, &admin);
+
+    // Daily cap is 1,000,000 points, so earn over two days
+    add_points_directly(&env, &client, &user, 1_000_000);
+    env.ledger().with_mut(|li| li.timestamp += 24 * 60 * 60);
+    add_points_directly(&env, &client, &user, 1_000_000);
+    let points = client.get_user_rewards(&user).total_points;
+
+    // 1,000,000 points waive the whole penalty; more is rejected
+    client.redeem_points(&user, &1_000_000);
+    assert_eq!(client.get_penalty_discount(&user), 10_000);
+    assert_eq!(
+        client.try_redeem_points(&user, &100),
+        Err(Ok(SavingsError::InvalidAmount))
+    );
+    assert_eq!(
+        client.get_user_rewards(&user).total_points,
+        points - 1_000_000
+    );
+}
+
+#[test]
+fn test_redeem_points_rejects_partial_discount_step() {
+    let (env, client, admin, user) = create_test_env();
+    setup_rewards_config(&env, &client, &admin);
+
+    add_points_directly(&env, &client, &user, 10_000);
+    let points = client.get_user_rewards(&user).total_points;
+
+    // 150 points would buy 1 bps and silently burn the other 50
+    assert_eq!(
+        client.try_redeem_points(&user, &150),
+        Err(Ok(SavingsError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_redeem_points(&user, &50),
+        Err(Ok(SavingsError::InvalidAmount))
+    );
+    assert_eq!(client.get_user_rewards(&user).total_points, points);
+    assert_eq!(client.get_penalty_discount(&user), 0);
+
+    client.redeem_points(&user, &200);
+    assert_eq!(client.get_user_rewards(&user).total_points, points - 200);
+    assert_eq!(client.get_penalty_discount(&user), 2);
+}